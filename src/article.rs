@@ -0,0 +1,184 @@
+//! This module contains the `Article` struct, which scaffolds a blog-style `<article>`
+
+use crate::{Html, HtmlChild, HtmlContainer, HtmlElement, HtmlTag};
+
+/// A blog-style `<article>` with a header, body content, and an optional footer
+///
+/// The header always contains an `<h1>` title, followed by a `<time>` tag if a publication date is
+/// set. The title is plain text, and is escaped. `Article` implements [`HtmlContainer`], and any
+/// content added through it is placed between the header and the footer.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let article = Article::new("Hello, World")
+///     .with_published("March 1st, 2024", "2024-03-01")
+///     .with_paragraph("My first post")
+///     .with_footer("Posted by Joe")
+///     .to_html_string();
+///
+/// assert_eq!(
+///     article,
+///     concat!(
+///         "<article><header><h1>Hello, World</h1>",
+///         r#"<time datetime="2024-03-01">March 1st, 2024</time></header>"#,
+///         "<p>My first post</p>",
+///         "<footer>Posted by Joe</footer></article>"
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Article {
+    header: HtmlElement,
+    body: Vec<HtmlChild>,
+    footer: Option<HtmlElement>,
+}
+
+impl Html for Article {
+    fn to_html_string(&self) -> String {
+        let mut html = String::new();
+        self.render_into(&mut html);
+        html
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        buf.push_str("<article>");
+        self.header.render_into(buf);
+        for child in self.body.iter() {
+            child.render_into(buf);
+        }
+        if let Some(footer) = &self.footer {
+            footer.render_into(buf);
+        }
+        buf.push_str("</article>");
+    }
+}
+
+impl HtmlContainer for Article {
+    fn add_html<H: Html>(&mut self, html: H) {
+        self.body.push(HtmlChild::Raw(html.to_html_string()));
+    }
+
+    fn add_element(&mut self, element: HtmlElement) {
+        self.body.push(HtmlChild::Element(element));
+    }
+}
+
+impl From<Article> for HtmlElement {
+    fn from(article: Article) -> Self {
+        let mut element = HtmlElement::new(HtmlTag::Article).with_child(article.header.into());
+        element.add_children(article.body);
+        if let Some(footer) = article.footer {
+            element.add_child(footer.into());
+        }
+        element
+    }
+}
+
+impl Article {
+    /// Creates a new `Article` with the specified title, which is escaped
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let article = Article::new("Q&A <draft>").to_html_string();
+    /// assert_eq!(article, "<article><header><h1>Q&amp;A &lt;draft&gt;</h1></header></article>");
+    /// ```
+    pub fn new(title: impl ToString) -> Self {
+        Self {
+            header: HtmlElement::new(HtmlTag::Header)
+                .with_child(HtmlElement::new_with_text_escaped(HtmlTag::Heading1, title).into()),
+            body: Vec::new(),
+            footer: None,
+        }
+    }
+
+    /// Set the publication date of this article, replacing any previous date
+    ///
+    /// `display` is shown to readers, while `datetime` is used as the machine-readable `datetime`
+    /// attribute of the `<time>` tag.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut article = Article::new("Title");
+    /// article.add_published("Yesterday", "2024-02-29");
+    /// article.add_published("Today", "2024-03-01");
+    ///
+    /// assert_eq!(
+    ///     article.to_html_string(),
+    ///     concat!(
+    ///         "<article><header><h1>Title</h1>",
+    ///         r#"<time datetime="2024-03-01">Today</time>"#,
+    ///         "</header></article>"
+    ///     )
+    /// );
+    /// ```
+    pub fn add_published(&mut self, display: impl ToString, datetime: impl ToString) {
+        self.header.children.truncate(1);
+        self.header.add_timestamp(display, datetime);
+    }
+
+    /// Set the publication date of this article, replacing any previous date
+    ///
+    /// `display` is shown to readers, while `datetime` is used as the machine-readable `datetime`
+    /// attribute of the `<time>` tag.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let article = Article::new("Title")
+    ///     .with_published("March 1st, 2024", "2024-03-01")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     article,
+    ///     concat!(
+    ///         "<article><header><h1>Title</h1>",
+    ///         r#"<time datetime="2024-03-01">March 1st, 2024</time>"#,
+    ///         "</header></article>"
+    ///     )
+    /// );
+    /// ```
+    pub fn with_published(mut self, display: impl ToString, datetime: impl ToString) -> Self {
+        self.add_published(display, datetime);
+        self
+    }
+
+    /// Set the footer of this article, replacing any previous footer
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut article = Article::new("Title");
+    /// article.add_footer(HtmlElement::new_with_text(HtmlTag::Link, "Comments"));
+    ///
+    /// assert_eq!(
+    ///     article.to_html_string(),
+    ///     "<article><header><h1>Title</h1></header><footer><a>Comments</a></footer></article>"
+    /// );
+    /// ```
+    pub fn add_footer(&mut self, footer: impl Html) {
+        self.footer = Some(HtmlElement::new(HtmlTag::Footer).with_html(footer));
+    }
+
+    /// Set the footer of this article, replacing any previous footer
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let article = Article::new("Title")
+    ///     .with_footer("Posted by Joe")
+    ///     .with_paragraph("Text")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     article,
+    ///     "<article><header><h1>Title</h1></header><p>Text</p><footer>Posted by Joe</footer></article>"
+    /// );
+    /// ```
+    pub fn with_footer(mut self, footer: impl Html) -> Self {
+        self.add_footer(footer);
+        self
+    }
+}
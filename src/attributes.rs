@@ -1,28 +1,99 @@
 //! This module contains the `Attributes` struct which defines a collection of
 //! attributes which can be added to an HTML tag.
 
+use crate::Escaped;
 use std::fmt;
-use std::fmt::Write;
 use std::iter::FromIterator;
 
+/// An ordered collection of `(key, value)` attribute pairs
+///
+/// Attributes are stored as-is and only converted to a string when displayed. Keys are unique:
+/// if the same key is specified more than once, the last value wins, but the attribute keeps the
+/// position where it first appeared. This is the same rule followed by
+/// [`HtmlElement::add_attribute`](crate::HtmlElement::add_attribute).
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
-pub struct Attributes(String);
+pub struct Attributes(Vec<(String, String)>);
 
 impl fmt::Display for Attributes {
     /// Converts this set of `Attributes` to an attribute string.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(&self.0)
+        for (k, v) in self.0.iter() {
+            QuoteStyle::Double.write_attribute(f, k, v)?;
+        }
+        Ok(())
+    }
+}
+
+/// The quote character used to delimit attribute values when rendering
+///
+/// Attribute values are always escaped, so values containing either quote character (or `&`,
+/// `<`, or `>`) have them replaced with the equivalent character references and the output
+/// remains well-formed.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let element = HtmlElement::new(HtmlTag::Div).with_attribute("title", "It's here");
+///
+/// assert_eq!(
+///     element.to_html_string_with_quotes(QuoteStyle::Single),
+///     "<div title='It&#39;s here'/>"
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum QuoteStyle {
+    /// Wrap values in double quotes: `class="x"`
+    #[default]
+    Double,
+    /// Wrap values in single quotes: `class='x'`
+    Single,
+}
+
+impl QuoteStyle {
+    /// Write a single ` key="value"` pair using this quote style
+    pub(crate) fn write_attribute(self, f: &mut fmt::Formatter, k: &str, v: &str) -> fmt::Result {
+        match self {
+            Self::Double => write!(f, r#" {}="{}""#, k, Escaped(v)),
+            Self::Single => write!(f, " {}='{}'", k, Escaped(v)),
+        }
+    }
+}
+
+/// The values of the `dir` attribute, which sets the direction of an element's text
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let element = HtmlElement::new_with_text(HtmlTag::ParagraphText, "مرحبا").with_dir(Direction::Rtl);
+/// assert_eq!(element.to_html_string(), r#"<p dir="rtl">مرحبا</p>"#);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    /// Left-to-right text, such as English
+    Ltr,
+    /// Right-to-left text, such as Arabic or Hebrew
+    Rtl,
+    /// Let the browser determine the direction from the content
+    Auto,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ltr => "ltr",
+            Self::Rtl => "rtl",
+            Self::Auto => "auto",
+        })
     }
 }
 
 impl<I: IntoIterator<Item = (S, S)>, S: ToString> From<I> for Attributes {
     fn from(iter: I) -> Self {
-        let mut attributes = String::new();
+        let mut attributes = Self::default();
         for (k, v) in iter.into_iter() {
-            write!(attributes, r#" {}="{}""#, k.to_string(), v.to_string())
-                .expect("Failed to write into String");
+            attributes.insert(k, v);
         }
-        Self(attributes)
+        attributes
     }
 }
 
@@ -31,3 +102,58 @@ impl<S: ToString> FromIterator<(S, S)> for Attributes {
         iter.into()
     }
 }
+
+impl Attributes {
+    /// Set the value of the given attribute, replacing any previous value for the same key
+    ///
+    /// If the key is already present, its value is replaced but it keeps its original position.
+    /// Otherwise, the attribute is added to the end.
+    pub fn insert(&mut self, k: impl ToString, v: impl ToString) {
+        set_attribute(&mut self.0, k.to_string(), v.to_string());
+    }
+}
+
+/// Set `key` to `value` in a list of attributes, replacing the value of an existing `key` in place
+pub(crate) fn set_attribute(list: &mut Vec<(String, String)>, key: String, value: String) {
+    match list.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, v)) => *v = value,
+        None => list.push((key, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        // Act
+        let sut = Attributes::from([("id", "main"), ("class", "wide")]);
+
+        // Assert
+        assert_eq!(sut.to_string(), r#" id="main" class="wide""#);
+        assert_eq!(Attributes::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        // Act
+        let sut = Attributes::from([("id", "first"), ("class", "wide"), ("id", "second")]);
+
+        // Assert
+        assert_eq!(sut.to_string(), r#" id="second" class="wide""#);
+    }
+
+    #[test]
+    fn test_insert_keeps_first_position() {
+        // Arrange
+        let mut sut = Attributes::from([("id", "first"), ("class", "wide")]);
+
+        // Act
+        sut.insert("id", "second");
+        sut.insert("role", "main");
+
+        // Assert
+        assert_eq!(sut.to_string(), r#" id="second" class="wide" role="main""#);
+    }
+}
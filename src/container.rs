@@ -2,6 +2,7 @@
 
 use crate::{Html, HtmlContainer, HtmlElement, HtmlTag};
 use std::fmt::{self, Display};
+use std::io;
 
 /// The different types of HTML containers that can be added to the page
 #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -11,11 +12,17 @@ pub enum ContainerType {
     Address,
     /// Corresponds to `<article>` tags
     Article,
+    /// Corresponds to `<aside>` tags
+    Aside,
+    /// Corresponds to `<blockquote>` tags
+    Blockquote,
     /// Corresponds to `<div>` tags
     ///
     /// This type is also the default for `Container`s
     #[default]
     Div,
+    /// Corresponds to `<figure>` tags
+    Figure,
     /// Corresponds to `<footer>` tags
     Footer,
     /// Corresponds to `<header>` tags
@@ -37,7 +44,10 @@ impl From<ContainerType> for HtmlTag {
         match value {
             ContainerType::Address => HtmlTag::Address,
             ContainerType::Article => HtmlTag::Article,
+            ContainerType::Aside => HtmlTag::Aside,
+            ContainerType::Blockquote => HtmlTag::Blockquote,
             ContainerType::Div => HtmlTag::Div,
+            ContainerType::Figure => HtmlTag::Figure,
             ContainerType::Footer => HtmlTag::Footer,
             ContainerType::Header => HtmlTag::Header,
             ContainerType::Main => HtmlTag::Main,
@@ -63,6 +73,19 @@ impl Display for ContainerType {
 ///
 /// Supported container types are provided by the [`ContainerType`] enum.
 ///
+/// ```rust
+/// # use build_html::*;
+/// let figure = Container::new(ContainerType::Figure)
+///     .with_container(Container::new(ContainerType::Blockquote).with_paragraph("Quote"))
+///     .with_html(HtmlElement::new(HtmlTag::Figcaption).with_child("Author".into()))
+///     .to_html_string();
+///
+/// assert_eq!(
+///     figure,
+///     "<figure><blockquote><p>Quote</p></blockquote><figcaption>Author</figcaption></figure>"
+/// );
+/// ```
+///
 /// Note that `Container` elements can be nested inside of each other.
 /// ```rust
 /// # use build_html::*;
@@ -82,8 +105,24 @@ impl Display for ContainerType {
 ///     "<main><h1>My Container</h1><article><div><p>Inner Text</p></div></article></main>"
 /// );
 /// ```
-#[derive(Debug)]
-pub struct Container(HtmlElement);
+///
+/// A `Container` can be cloned, so a component can be built once and inserted in several places.
+/// ```rust
+/// # use build_html::*;
+/// let notice = Container::default().with_paragraph("Beta");
+/// let page = Container::new(ContainerType::Main)
+///     .with_container(notice.clone())
+///     .with_paragraph("Content")
+///     .with_container(notice)
+///     .to_html_string();
+///
+/// assert_eq!(
+///     page,
+///     "<main><div><p>Beta</p></div><p>Content</p><div><p>Beta</p></div></main>"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Container(pub(crate) HtmlElement);
 
 impl Default for Container {
     fn default() -> Self {
@@ -95,6 +134,18 @@ impl Html for Container {
     fn to_html_string(&self) -> String {
         self.0.to_html_string()
     }
+
+    fn render_into(&self, buf: &mut String) {
+        self.0.render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        self.0.to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        self.0.write_html(w)
+    }
 }
 
 impl HtmlContainer for Container {
@@ -108,6 +159,17 @@ impl HtmlContainer for Container {
             _ => self.0.add_html(content),
         };
     }
+
+    fn add_element(&mut self, element: HtmlElement) {
+        match self.0.tag {
+            HtmlTag::OrderedList | HtmlTag::UnorderedList => self.0.add_child(
+                HtmlElement::new(HtmlTag::ListElement)
+                    .with_element(element)
+                    .into(),
+            ),
+            _ => self.0.add_element(element),
+        };
+    }
 }
 
 impl Container {
@@ -118,8 +180,8 @@ impl Container {
 
     /// Associates the specified map of attributes with this Container.
     ///
-    /// Note that this operation overrides all previous `with_attribute` calls on
-    /// this `Container`
+    /// These attributes are appended to any which have already been set on this `Container`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -136,9 +198,81 @@ impl Container {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        for (k, v) in attributes {
-            self.0.add_attribute(k, v);
-        }
+        self.0.add_attributes(attributes);
+        self
+    }
+
+    /// Add a class to this `Container`, appending it to any existing `class` attribute
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let container = Container::default()
+    ///     .with_attributes([("class", "card")])
+    ///     .with_class("wide")
+    ///     .with_paragraph("text")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(container, r#"<div class="card wide"><p>text</p></div>"#)
+    /// ```
+    pub fn with_class(mut self, class: impl ToString) -> Self {
+        self.0.add_class(class);
+        self
+    }
+
+    /// Adds the specified content wrapped in an `<li>` tag with the specified attributes
+    ///
+    /// Content added to list containers through the [`HtmlContainer`] methods is wrapped in a
+    /// bare `<li>` automatically. This method allows attributes to be set on that wrapper instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut list = Container::new(ContainerType::OrderedList);
+    /// list.add_list_item_attr("First", [("value", "10")]);
+    /// list.add_paragraph("Second");
+    ///
+    /// assert_eq!(
+    ///     list.to_html_string(),
+    ///     r#"<ol><li value="10">First</li><li><p>Second</p></li></ol>"#
+    /// );
+    /// ```
+    pub fn add_list_item_attr<H, A, S>(&mut self, content: H, attributes: A)
+    where
+        H: Html,
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        let mut item = HtmlElement::new(HtmlTag::ListElement).with_html(content);
+        item.add_attributes(attributes);
+        self.0.add_child(item.into());
+    }
+
+    /// Adds the specified content wrapped in an `<li>` tag with the specified attributes
+    ///
+    /// Content added to list containers through the [`HtmlContainer`] methods is wrapped in a
+    /// bare `<li>` automatically. This method allows attributes to be set on that wrapper instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let list = Container::new(ContainerType::UnorderedList)
+    ///     .with_list_item_attr("Done", [("class", "complete")])
+    ///     .with_list_item_attr("Not done", [("class", "pending")])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     list,
+    ///     r#"<ul><li class="complete">Done</li><li class="pending">Not done</li></ul>"#
+    /// );
+    /// ```
+    pub fn with_list_item_attr<H, A, S>(mut self, content: H, attributes: A) -> Self
+    where
+        H: Html,
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_list_item_attr(content, attributes);
         self
     }
 }
@@ -0,0 +1,146 @@
+//! This module contains the `Details` struct, used to build `<details>` disclosure widgets
+
+use crate::{Html, HtmlChild, HtmlContainer, HtmlElement, HtmlTag};
+use std::io;
+
+/// A `<details>` disclosure widget with a `<summary>` label
+///
+/// `Details` implements [`HtmlContainer`], so its body can hold any content, including tables and
+/// other nested `Details`. The summary is always rendered as the first child, regardless of when
+/// it was set.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let details = Details::new()
+///     .with_paragraph("Hidden text")
+///     .with_summary("More")
+///     .with_html(Details::new().with_summary("Even more").with_paragraph("Nested"))
+///     .to_html_string();
+///
+/// assert_eq!(
+///     details,
+///     concat!(
+///         "<details><summary>More</summary><p>Hidden text</p>",
+///         "<details><summary>Even more</summary><p>Nested</p></details>",
+///         "</details>"
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Details(HtmlElement);
+
+impl Default for Details {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Html for Details {
+    fn to_html_string(&self) -> String {
+        self.0.to_html_string()
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        self.0.render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        self.0.to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        self.0.write_html(w)
+    }
+}
+
+impl HtmlContainer for Details {
+    fn add_html<H: Html>(&mut self, html: H) {
+        self.0.add_html(html);
+    }
+
+    fn add_element(&mut self, element: HtmlElement) {
+        self.0.add_element(element);
+    }
+}
+
+impl From<Details> for HtmlElement {
+    fn from(details: Details) -> Self {
+        details.0
+    }
+}
+
+impl Details {
+    /// Creates a new `Details` with no summary or content
+    pub fn new() -> Self {
+        Self(HtmlElement::new(HtmlTag::Details))
+    }
+
+    /// Set the summary shown while this widget is closed, replacing any previous summary
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut details = Details::new().with_summary("Old");
+    /// details.add_summary("Specifications");
+    /// details.add_table(Table::from([["Weight", "2kg"]]));
+    ///
+    /// assert_eq!(
+    ///     details.to_html_string(),
+    ///     concat!(
+    ///         "<details><summary>Specifications</summary>",
+    ///         "<table><thead/><tbody><tr><td>Weight</td><td>2kg</td></tr></tbody></table>",
+    ///         "</details>"
+    ///     )
+    /// );
+    /// ```
+    pub fn add_summary(&mut self, summary: impl Html) {
+        let summary = HtmlElement::new(HtmlTag::Summary).with_html(summary);
+        match self.0.children.first_mut() {
+            Some(HtmlChild::Element(e)) if e.tag == HtmlTag::Summary => *e = summary,
+            _ => self.0.children.insert(0, summary.into()),
+        }
+    }
+
+    /// Set the summary shown while this widget is closed, replacing any previous summary
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let details = Details::new()
+    ///     .with_summary("More")
+    ///     .with_paragraph("Hidden text")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(details, "<details><summary>More</summary><p>Hidden text</p></details>");
+    /// ```
+    pub fn with_summary(mut self, summary: impl Html) -> Self {
+        self.add_summary(summary);
+        self
+    }
+
+    /// Associates the specified map of attributes with this `Details`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let details = Details::new()
+    ///     .with_attributes([("class", "faq")])
+    ///     .with_summary("Question")
+    ///     .with_paragraph("Answer")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     details,
+    ///     r#"<details class="faq"><summary>Question</summary><p>Answer</p></details>"#
+    /// )
+    /// ```
+    pub fn with_attributes<A, S>(mut self, attributes: A) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.0.add_attributes(attributes);
+        self
+    }
+}
@@ -0,0 +1,145 @@
+//! This module contains the `Dialog` struct, used to build native `<dialog>` elements
+
+use crate::{Html, HtmlContainer, HtmlElement, HtmlTag};
+use std::io;
+
+/// A native HTML `<dialog>` element
+///
+/// `Dialog` implements [`HtmlContainer`], so it can be filled just like any other
+/// [`Container`](crate::Container). Dialogs are hidden by default; use
+/// [`with_open`](Dialog::with_open) to set the boolean `open` attribute. A dialog always has a
+/// closing tag, even when it is empty.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let dialog = Dialog::new()
+///     .with_open()
+///     .with_paragraph("Are you sure?")
+///     .to_html_string();
+///
+/// assert_eq!(dialog, r#"<dialog open=""><p>Are you sure?</p></dialog>"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dialog(HtmlElement);
+
+impl Default for Dialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Html for Dialog {
+    fn to_html_string(&self) -> String {
+        self.0.to_html_string()
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        self.0.render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        self.0.to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        self.0.write_html(w)
+    }
+}
+
+impl HtmlContainer for Dialog {
+    fn add_html<H: Html>(&mut self, html: H) {
+        self.0.add_html(html);
+    }
+
+    fn add_element(&mut self, element: HtmlElement) {
+        self.0.add_element(element);
+    }
+}
+
+impl Dialog {
+    /// Creates a new, closed `Dialog` with no content
+    pub fn new() -> Self {
+        // `<dialog>` is not a void element, so the empty child forces a closing tag
+        Self(HtmlElement::new(HtmlTag::Dialog).with_child("".into()))
+    }
+
+    /// Mark this dialog as open, so that it is shown when the page loads
+    ///
+    /// This sets `open=""`, which is how the boolean attribute is written in both HTML and XHTML.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut dialog = Dialog::new().with_attributes([("id", "confirm")]);
+    /// dialog.add_open();
+    /// dialog.add_paragraph("Continue?");
+    ///
+    /// assert_eq!(
+    ///     dialog.to_html_string(),
+    ///     r#"<dialog id="confirm" open=""><p>Continue?</p></dialog>"#
+    /// );
+    /// ```
+    pub fn add_open(&mut self) {
+        self.0.add_attribute("open", "");
+    }
+
+    /// Mark this dialog as open, so that it is shown when the page loads
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let dialog = Dialog::new()
+    ///     .with_open()
+    ///     .with_paragraph("Hello")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(dialog, r#"<dialog open=""><p>Hello</p></dialog>"#);
+    /// ```
+    pub fn with_open(mut self) -> Self {
+        self.add_open();
+        self
+    }
+
+    /// Associates the specified map of attributes with this `Dialog`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let dialog = Dialog::new()
+    ///     .with_attributes([("id", "confirm"), ("class", "modal")])
+    ///     .with_paragraph("text")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(dialog, r#"<dialog id="confirm" class="modal"><p>text</p></dialog>"#)
+    /// ```
+    pub fn with_attributes<A, S>(mut self, attributes: A) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.0.add_attributes(attributes);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_dialog() {
+        // Act
+        let closed = Dialog::new();
+        let open = Dialog::new().with_open().with_open();
+
+        // Assert
+        assert_eq!(closed.to_html_string(), "<dialog></dialog>");
+        assert_eq!(open.to_html_string(), r#"<dialog open=""></dialog>"#);
+        assert_eq!(open.to_html_string_pretty(2), open.to_html_string());
+
+        let mut streamed = Vec::new();
+        open.write_html(&mut streamed).unwrap();
+        assert_eq!(String::from_utf8(streamed).unwrap(), open.to_html_string());
+    }
+}
@@ -1,13 +1,20 @@
 //! Definitions for generic HTML tags
 
-use crate::{Html, HtmlContainer, HtmlTag};
-use std::fmt::{self, Display, Formatter};
+use crate::attributes::set_attribute;
+use crate::{
+    comment_html, escape_html, escape_html_into, Comment, Direction, Escaped, Html, HtmlContainer,
+    HtmlTag, QuoteStyle,
+};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter, Write};
+use std::io;
 
 /// A child of an [`HtmlElement`]: either another element, or some raw text
 ///
 /// Generally, `HtmlContent` shouldn't need to be used directly. You can use `.into()` to convert
 /// strings and [`HtmlElement`]s into this type. For example:
-/// 
+///
 /// ```
 /// # use build_html::*;
 /// let html = HtmlElement::new(HtmlTag::Div)
@@ -19,18 +26,146 @@ use std::fmt::{self, Display, Formatter};
 ///             .into() // Convert this `HtmlElement` into an `HtmlChild::Element`
 ///     )
 ///     .to_html_string();
-/// 
+///
 /// assert_eq!(html, "<div><p>raw text</p></div>")
 /// ```
-#[derive(Debug, Clone)]
+///
+/// New kinds of child may be added in the future, so a `match` on this enum from outside this
+/// crate needs a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum HtmlChild {
     /// An element that can have more children of its own
     Element(HtmlElement),
 
     /// A raw string that will be appended into the output HTML
     ///
-    /// This is an escape hatch you can use to inject any data into your HTML
+    /// This is an escape hatch you can use to inject any data into your HTML. It is *not*
+    /// escaped, so it should only hold trusted markup; this is the variant produced by converting
+    /// a string with `into`. Use [`Text`](HtmlChild::Text) for untrusted text.
     Raw(String),
+
+    /// Plain text, which is escaped with [`escape_html`] when rendered
+    ///
+    /// This is the safe choice for text from untrusted sources. Create it with
+    /// [`HtmlChild::text`].
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::ParagraphText)
+    ///     .with_child(HtmlChild::text("a < b"))
+    ///     .with_child(HtmlElement::new(HtmlTag::LineBreak).into())
+    ///     .with_child("<b>raw</b>".into());
+    ///
+    /// assert_eq!(element.to_html_string(), "<p>a &lt; b<br/><b>raw</b></p>");
+    /// ```
+    Text(String),
+
+    /// An HTML comment, rendered as `<!--...-->`
+    ///
+    /// Sequences which could end the comment early are neutralized in the same way as
+    /// [`Comment`](crate::Comment).
+    /// Comments are kept as distinct nodes so that they survive traversal, but they are never
+    /// matched by queries over elements.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_child(HtmlChild::Comment(" begin list ".into()))
+    ///     .with_child(HtmlElement::new(HtmlTag::UnorderedList).into());
+    ///
+    /// assert_eq!(element.to_html_string(), "<div><!-- begin list --><ul/></div>");
+    /// assert_eq!(element.descendant_count(), 1);
+    /// ```
+    Comment(String),
+}
+
+impl HtmlChild {
+    /// Create a text child, which will be escaped when rendered
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let child = HtmlChild::text("Fish & Chips");
+    /// assert_eq!(child, HtmlChild::Text("Fish & Chips".to_string()));
+    /// assert_eq!(child.to_html_string(), "Fish &amp; Chips");
+    /// ```
+    pub fn text(text: impl ToString) -> Self {
+        Self::Text(text.to_string())
+    }
+
+    /// Get a reference to the inner element, if this child is an element
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let child: HtmlChild = HtmlElement::new(HtmlTag::Div).into();
+    /// assert_eq!(child.as_element().map(|e| &e.tag), Some(&HtmlTag::Div));
+    /// assert!(HtmlChild::from("text").as_element().is_none());
+    /// ```
+    pub fn as_element(&self) -> Option<&HtmlElement> {
+        match self {
+            Self::Element(e) => Some(e),
+            Self::Raw(_) | Self::Text(_) | Self::Comment(_) => None,
+        }
+    }
+
+    /// Get a mutable reference to the inner element, if this child is an element
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut child: HtmlChild = HtmlElement::new(HtmlTag::Div).into();
+    /// if let Some(element) = child.as_element_mut() {
+    ///     element.add_attribute("id", "main");
+    /// }
+    /// assert_eq!(child.to_html_string(), r#"<div id="main"/>"#);
+    /// ```
+    pub fn as_element_mut(&mut self) -> Option<&mut HtmlElement> {
+        match self {
+            Self::Element(e) => Some(e),
+            Self::Raw(_) | Self::Text(_) | Self::Comment(_) => None,
+        }
+    }
+
+    /// Get the raw string, if this child is raw text
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!(HtmlChild::from("text").as_raw(), Some("text"));
+    /// assert_eq!(HtmlChild::from(HtmlElement::new(HtmlTag::Div)).as_raw(), None);
+    /// ```
+    pub fn as_raw(&self) -> Option<&str> {
+        match self {
+            Self::Raw(r) => Some(r),
+            Self::Element(_) | Self::Text(_) | Self::Comment(_) => None,
+        }
+    }
+
+    /// Get the unescaped text, if this child is text
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!(HtmlChild::text("a < b").as_text(), Some("a < b"));
+    /// assert_eq!(HtmlChild::from("a < b").as_text(), None);
+    /// ```
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(t) => Some(t),
+            Self::Element(_) | Self::Raw(_) | Self::Comment(_) => None,
+        }
+    }
+
+    /// Get the text of the comment, if this child is a comment
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!(HtmlChild::Comment("note".into()).as_comment(), Some("note"));
+    /// assert_eq!(HtmlChild::from("text").as_comment(), None);
+    /// ```
+    pub fn as_comment(&self) -> Option<&str> {
+        match self {
+            Self::Comment(c) => Some(c),
+            Self::Element(_) | Self::Raw(_) | Self::Text(_) => None,
+        }
+    }
 }
 
 impl Display for HtmlChild {
@@ -38,15 +173,48 @@ impl Display for HtmlChild {
         match self {
             Self::Element(e) => write!(f, "{e}"),
             Self::Raw(r) => write!(f, "{r}"),
+            Self::Text(t) => write!(f, "{}", Escaped(t)),
+            Self::Comment(c) => write!(f, "{}", comment_html(c)),
         }
     }
 }
 
 impl Html for HtmlChild {
     fn to_html_string(&self) -> String {
+        self.to_html_cow().into_owned()
+    }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
         match self {
-            Self::Element(e) => e.to_html_string(),
-            Self::Raw(r) => r.to_owned(),
+            Self::Element(e) => e.to_html_cow(),
+            Self::Raw(r) => Cow::Borrowed(r),
+            Self::Text(t) => Cow::Owned(escape_html(t)),
+            Self::Comment(c) => Cow::Owned(comment_html(c)),
+        }
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        match self {
+            Self::Element(e) => e.render_into(buf),
+            Self::Raw(r) => buf.push_str(r),
+            Self::Text(t) => escape_html_into(t, buf),
+            Self::Comment(c) => buf.push_str(&comment_html(c)),
+        }
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        match self {
+            Self::Element(e) => e.to_html_string_pretty(indent),
+            _ => self.to_html_string(),
+        }
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        match self {
+            Self::Element(e) => e.write_html(w),
+            Self::Raw(r) => w.write_all(r.as_bytes()),
+            Self::Text(t) => write!(w, "{}", Escaped(t)),
+            Self::Comment(c) => w.write_all(comment_html(c).as_bytes()),
         }
     }
 }
@@ -57,6 +225,12 @@ impl From<HtmlElement> for HtmlChild {
     }
 }
 
+impl From<Comment> for HtmlChild {
+    fn from(value: Comment) -> Self {
+        Self::Comment(value.0)
+    }
+}
+
 impl<S: AsRef<str>> From<S> for HtmlChild {
     fn from(value: S) -> Self {
         Self::Raw(value.as_ref().to_owned())
@@ -90,7 +264,29 @@ impl<S: AsRef<str>> From<S> for HtmlChild {
 ///
 /// assert_eq!(output, r#"<div><h1 class="big-text">Header Text</h1><p>Paragraph Text<br/>Paragraph Text Line 2</p></div>"#);
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Elements can be compared structurally: two elements are equal if they have the same tag, the
+/// same attributes in the same order, and equal children.
+///
+/// ```
+/// # use build_html::*;
+/// let a = HtmlElement::new(HtmlTag::Div).with_child("text".into());
+/// let b = HtmlElement::new(HtmlTag::Div).with_child(HtmlChild::Raw("text".to_string()));
+/// assert_eq!(a, b);
+/// assert_ne!(a, b.with_attribute("id", "b"));
+/// ```
+///
+/// Cloning an element is a deep copy: the clone owns its own attributes and children, so changes
+/// to one do not affect the other.
+///
+/// ```
+/// # use build_html::*;
+/// let original = HtmlElement::new(HtmlTag::Div).with_paragraph("Text");
+/// let mut copy = original.clone();
+/// copy.add_attribute("id", "copy");
+/// assert_eq!(original.to_html_string(), "<div><p>Text</p></div>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HtmlElement {
     /// The tag to be used for this element
     pub tag: HtmlTag,
@@ -102,17 +298,35 @@ pub struct HtmlElement {
 
 impl Display for HtmlElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        if self.children.is_empty() {
-            write!(f, "<{}", self.tag)?;
-            self.write_attributes(f)?;
-            write!(f, "/>")
-        } else {
-            write!(f, "<{}", self.tag,)?;
-            self.write_attributes(f)?;
-            write!(f, ">")?;
-            self.write_children(f)?;
-            write!(f, "</{}>", self.tag)
-        }
+        self.write_element(f, QuoteStyle::Double)
+    }
+}
+
+/// Whether an element with the given tag must have a closing tag, even when it is empty
+///
+/// An HTML parser ignores the `/` in `<textarea/>`, so a self-closed form control would swallow
+/// the rest of the document.
+pub(crate) fn requires_closing_tag(tag: &HtmlTag) -> bool {
+    matches!(tag, HtmlTag::Label | HtmlTag::Select | HtmlTag::TextArea)
+}
+
+/// Adapter used to display an element with a non-default [`QuoteStyle`]
+struct Quoted<'a>(&'a HtmlElement, QuoteStyle);
+
+impl Display for Quoted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.write_element(f, self.1)
+    }
+}
+
+/// Adapter used to display only the opening tag of an element, with its attributes
+pub(crate) struct OpenTag<'a>(&'a HtmlElement);
+
+impl Display for OpenTag<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}", self.0.tag)?;
+        self.0.write_attributes(f, QuoteStyle::Double)?;
+        write!(f, ">")
     }
 }
 
@@ -120,6 +334,23 @@ impl Html for HtmlElement {
     fn to_html_string(&self) -> String {
         format!("{}", self)
     }
+
+    fn render_into(&self, buf: &mut String) {
+        // Writing to a `String` never fails
+        let _ = write!(buf, "{}", self);
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        let mut html = String::new();
+        self.write_pretty(&mut html, indent, 0);
+        html.pop();
+        html
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        // Formatting directly into the writer streams the element without building a `String`
+        write!(w, "{}", self)
+    }
 }
 
 /// This implementation of HtmlContainer allows seamless for compatibility between the "easy"
@@ -128,6 +359,10 @@ impl HtmlContainer for HtmlElement {
     fn add_html<H: Html>(&mut self, html: H) {
         self.children.push(HtmlChild::Raw(html.to_html_string()))
     }
+
+    fn add_element(&mut self, element: HtmlElement) {
+        self.children.push(HtmlChild::Element(element))
+    }
 }
 
 impl HtmlElement {
@@ -145,6 +380,60 @@ impl HtmlElement {
         }
     }
 
+    /// Create a new HTML element with the given tag, containing the given text
+    ///
+    /// The text is added as an [`HtmlChild::Raw`], so it is *not* escaped. Use
+    /// [`new_with_text_escaped`](HtmlElement::new_with_text_escaped) for untrusted input.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new_with_text(HtmlTag::ParagraphText, "Hello, <b>World</b>");
+    /// assert_eq!(element.to_html_string(), "<p>Hello, <b>World</b></p>");
+    /// ```
+    pub fn new_with_text(tag: HtmlTag, text: impl ToString) -> Self {
+        Self::new(tag).with_child(HtmlChild::Raw(text.to_string()))
+    }
+
+    /// Create a new HTML element with the given tag, containing the given text after escaping it
+    /// with [`escape_html`](crate::escape_html)
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new_with_text_escaped(HtmlTag::ParagraphText, "1 < 2");
+    /// assert_eq!(element.to_html_string(), "<p>1 &lt; 2</p>");
+    /// ```
+    pub fn new_with_text_escaped(tag: HtmlTag, text: impl ToString) -> Self {
+        Self::new_with_text(tag, escape_html(&text.to_string()))
+    }
+
+    /// Create a `<label>` which wraps the given form control, preceded by the given text
+    ///
+    /// Nesting the control inside its label associates the two for assistive technology, without
+    /// needing matching `for` and `id` attributes. The text is *not* escaped.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let input = HtmlElement::new(HtmlTag::Input)
+    ///     .with_attribute("name", "email")
+    ///     .with_attribute("type", "email");
+    /// let label = HtmlElement::new_label("Email ", input);
+    ///
+    /// assert_eq!(
+    ///     label.to_html_string(),
+    ///     r#"<label>Email <input name="email" type="email"/></label>"#
+    /// );
+    ///
+    /// // Text areas and selects are never self-closed, even when they are empty
+    /// let bio = HtmlElement::new_label("Bio ", HtmlElement::new(HtmlTag::TextArea));
+    /// assert_eq!(bio.to_html_string(), "<label>Bio <textarea></textarea></label>");
+    /// let select = HtmlElement::new(HtmlTag::Select).with_attribute("name", "size");
+    /// assert_eq!(select.to_html_string(), r#"<select name="size"></select>"#);
+    /// assert_eq!(HtmlElement::new(HtmlTag::Label).to_html_string(), "<label></label>");
+    /// ```
+    pub fn new_label(text: impl ToString, control: HtmlElement) -> Self {
+        Self::new_with_text(HtmlTag::Label, text).with_child(control.into())
+    }
+
     /// Add a new child to this element
     ///
     /// A child can be either a raw string ([`HtmlChild::Raw`]) or another element
@@ -183,25 +472,206 @@ impl HtmlElement {
         self
     }
 
+    /// Add each of the specified children to this element, in order
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let children: Vec<HtmlChild> = vec![
+    ///     "First Line".into(),
+    ///     HtmlElement::new(HtmlTag::LineBreak).into(),
+    ///     "Second Line".into(),
+    /// ];
+    /// let mut element = HtmlElement::new(HtmlTag::ParagraphText);
+    /// element.add_children(children);
+    /// assert_eq!(element.to_html_string(), "<p>First Line<br/>Second Line</p>");
+    /// ```
+    pub fn add_children(&mut self, children: impl IntoIterator<Item = HtmlChild>) {
+        self.children.extend(children);
+    }
+
+    /// Consume this element and return it with each of the specified children appended, in order
+    ///
+    /// Unlike [`add_html`](HtmlContainer::add_html), this keeps the children structured rather
+    /// than converting them into strings.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let items = ["One", "Two", "Three"]
+    ///     .iter()
+    ///     .map(|x| HtmlElement::new_with_text(HtmlTag::ListElement, x).into());
+    /// let output = HtmlElement::new(HtmlTag::UnorderedList)
+    ///     .with_children(items)
+    ///     .to_html_string();
+    /// assert_eq!(output, "<ul><li>One</li><li>Two</li><li>Three</li></ul>");
+    /// ```
+    pub fn with_children(mut self, children: impl IntoIterator<Item = HtmlChild>) -> Self {
+        self.add_children(children);
+        self
+    }
+
+    /// Append raw text to this element, merging it into the last child if that child is raw
+    ///
+    /// This produces the same output as repeatedly adding [`HtmlChild::Raw`] children, but avoids
+    /// fragmenting the element into many small children when it is built up piece by piece.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::ParagraphText);
+    /// for word in ["Hello", ", ", "World"] {
+    ///     element.append_raw(word);
+    /// }
+    /// element.add_child(HtmlElement::new(HtmlTag::LineBreak).into());
+    /// element.append_raw("!");
+    ///
+    /// assert_eq!(element.children.len(), 3);
+    /// assert_eq!(element.to_html_string(), "<p>Hello, World<br/>!</p>");
+    /// ```
+    pub fn append_raw(&mut self, s: &str) {
+        match self.children.last_mut() {
+            Some(HtmlChild::Raw(raw)) => raw.push_str(s),
+            _ => self.children.push(HtmlChild::Raw(s.to_owned())),
+        }
+    }
+
+    /// Swap the children at the two specified indices
+    ///
+    /// Returns `false` and leaves the children unchanged if either index is out of bounds.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::ParagraphText)
+    ///     .with_child("First".into())
+    ///     .with_child(HtmlElement::new(HtmlTag::LineBreak).into())
+    ///     .with_child("Second".into());
+    ///
+    /// assert!(element.swap_children(0, 2));
+    /// assert!(!element.swap_children(0, 3));
+    /// assert_eq!(element.to_html_string(), "<p>Second<br/>First</p>");
+    /// ```
+    pub fn swap_children(&mut self, a: usize, b: usize) -> bool {
+        if a < self.children.len() && b < self.children.len() {
+            self.children.swap(a, b);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move the child at index `from` so that it ends up at index `to`
+    ///
+    /// The children between the two positions shift over by one to make room. Returns `false` and
+    /// leaves the children unchanged if either index is out of bounds.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let items = ["One", "Two", "Three"]
+    ///     .iter()
+    ///     .map(|x| HtmlElement::new_with_text(HtmlTag::ListElement, x).into());
+    /// let mut list = HtmlElement::new(HtmlTag::UnorderedList).with_children(items);
+    ///
+    /// assert!(list.move_child(2, 0));
+    /// assert!(!list.move_child(3, 0));
+    /// assert_eq!(list.to_html_string(), "<ul><li>Three</li><li>One</li><li>Two</li></ul>");
+    /// ```
+    pub fn move_child(&mut self, from: usize, to: usize) -> bool {
+        if from < self.children.len() && to < self.children.len() {
+            let child = self.children.remove(from);
+            self.children.insert(to, child);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Add a new child element with the given tag, built using the provided closure
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::UnorderedList);
+    /// for item in ["One", "Two"] {
+    ///     element.add_element_child(HtmlTag::ListElement, |li| li.with_child(item.into()));
+    /// }
+    /// assert_eq!(element.to_html_string(), "<ul><li>One</li><li>Two</li></ul>");
+    /// ```
+    pub fn add_element_child<F>(&mut self, tag: HtmlTag, build: F)
+    where
+        F: FnOnce(HtmlElement) -> HtmlElement,
+    {
+        self.add_child(build(HtmlElement::new(tag)).into());
+    }
+
+    /// Consume this element and return it with a new child element appended, where the child is
+    /// built using the provided closure
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let output = HtmlElement::new(HtmlTag::Div)
+    ///     .with_element_child(HtmlTag::ParagraphText, |p| {
+    ///         p.with_child("Some ".into())
+    ///             .with_element_child(HtmlTag::Span, |span| span.with_child("nested".into()))
+    ///             .with_child(" text".into())
+    ///     })
+    ///     .to_html_string();
+    /// assert_eq!(output, "<div><p>Some <span>nested</span> text</p></div>");
+    /// ```
+    pub fn with_element_child<F>(mut self, tag: HtmlTag, build: F) -> Self
+    where
+        F: FnOnce(HtmlElement) -> HtmlElement,
+    {
+        self.add_element_child(tag, build);
+        self
+    }
+
     /// Add an attribute to this element
     ///
-    /// This attribute will simply be appended to the others that have been specified. If the same
-    /// attribute is specified twice, it will be duplicated, which may result in strange behavior.
+    /// This attribute will be appended to the others that have been specified. If an attribute
+    /// with the same key has already been set, its value is replaced instead, and it keeps its
+    /// original position. Use [`push_attribute`](HtmlElement::push_attribute) to opt out of this.
+    ///
+    /// The value is stored as-is, and escaped when the element is rendered, so it cannot close the
+    /// attribute early. The key is not escaped.
     ///
     /// ```
     /// # use build_html::*;
     /// let mut element = HtmlElement::new(HtmlTag::Div);
     /// element.add_attribute("class", "container");
-    /// assert_eq!(element.to_html_string(), r#"<div class="container"/>"#);
+    /// element.add_attribute("title", r#"Say "hi" & <wave>"#);
+    /// assert_eq!(
+    ///     element.to_html_string(),
+    ///     r#"<div class="container" title="Say &quot;hi&quot; &amp; &lt;wave&gt;"/>"#
+    /// );
+    ///
+    /// element.add_attribute("class", "wide");
+    /// assert_eq!(
+    ///     element.to_html_string(),
+    ///     r#"<div class="wide" title="Say &quot;hi&quot; &amp; &lt;wave&gt;"/>"#
+    /// );
     /// ```
     pub fn add_attribute(&mut self, k: impl ToString, v: impl ToString) {
+        set_attribute(&mut self.attributes, k.to_string(), v.to_string());
+    }
+
+    /// Add an attribute to the end of this element, even if an attribute with the same key is set
+    ///
+    /// Unlike [`add_attribute`](HtmlElement::add_attribute), this never replaces an existing
+    /// value, so the attribute may be rendered more than once. Browsers only read the first
+    /// occurrence of a repeated attribute.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div).with_attribute("id", "first");
+    /// element.push_attribute("id", "second");
+    /// assert_eq!(element.to_html_string(), r#"<div id="first" id="second"/>"#);
+    /// ```
+    pub fn push_attribute(&mut self, k: impl ToString, v: impl ToString) {
         self.attributes.push((k.to_string(), v.to_string()));
     }
 
     /// Consume this element and return it with the given attribute set.
     ///
-    /// This attribute will simply be appended to the others that have been specified. If the same
-    /// attribute is specified twice, it will be duplicated, which may result in strange behavior.
+    /// This attribute will be appended to the others that have been specified. If an attribute
+    /// with the same key has already been set, its value is replaced instead, as with
+    /// [`add_attribute`](HtmlElement::add_attribute).
     ///
     /// ```
     /// # use build_html::*;
@@ -216,16 +686,519 @@ impl HtmlElement {
         self
     }
 
-    fn write_attributes(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    /// Add each of the specified attributes to this element
+    ///
+    /// These attributes will be appended to the others that have been specified, replacing the
+    /// values of any with the same key, as with [`add_attribute`](HtmlElement::add_attribute).
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div);
+    /// element.add_attributes([("id", "main"), ("class", "container")]);
+    /// assert_eq!(element.to_html_string(), r#"<div id="main" class="container"/>"#);
+    /// ```
+    pub fn add_attributes<A, S>(&mut self, attributes: A)
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        for (k, v) in attributes {
+            self.add_attribute(k, v);
+        }
+    }
+
+    /// Consume this element and return it with each of the specified attributes added
+    ///
+    /// These attributes will be appended to the others that have been specified, replacing the
+    /// values of any with the same key, as with [`with_attribute`](HtmlElement::with_attribute).
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let output = HtmlElement::new(HtmlTag::Div)
+    ///     .with_attributes([("id", "main"), ("class", "container")])
+    ///     .with_attribute("role", "region")
+    ///     .to_html_string();
+    /// assert_eq!(output, r#"<div id="main" class="container" role="region"/>"#);
+    /// ```
+    pub fn with_attributes<A, S>(mut self, attributes: A) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_attributes(attributes);
+        self
+    }
+
+    /// Add an HTML comment as the last child of this element
+    ///
+    /// The comment is stored as an [`HtmlChild::Comment`], so any sequence within the text which
+    /// could end the comment early is neutralized when rendered.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div);
+    /// element.add_comment(" generated 2024-01-01 ");
+    /// element.add_comment("a --> b");
+    /// assert_eq!(
+    ///     element.to_html_string(),
+    ///     "<div><!-- generated 2024-01-01 --><!--a --&gt; b--></div>"
+    /// );
+    /// ```
+    pub fn add_comment(&mut self, text: impl Into<String>) {
+        self.add_child(HtmlChild::Comment(text.into()));
+    }
+
+    /// Consume this element and return it with an HTML comment added as its last child
+    ///
+    /// The comment is stored as an [`HtmlChild::Comment`], so any sequence within the text which
+    /// could end the comment early is neutralized when rendered.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let output = HtmlElement::new(HtmlTag::Div)
+    ///     .with_comment("[if mso]><table><tr><td><![endif]")
+    ///     .with_paragraph("Content")
+    ///     .with_comment("[if mso]></td></tr></table><![endif]")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     output,
+    ///     concat!(
+    ///         "<div><!--[if mso]><table><tr><td><![endif]-->",
+    ///         "<p>Content</p>",
+    ///         "<!--[if mso]></td></tr></table><![endif]--></div>"
+    ///     )
+    /// );
+    /// ```
+    pub fn with_comment(mut self, text: impl Into<String>) -> Self {
+        self.add_comment(text);
+        self
+    }
+
+    /// Add a class to this element, appending it to any existing `class` attribute
+    ///
+    /// Unlike [`add_attribute`](HtmlElement::add_attribute), this keeps the classes which are
+    /// already set. If there is more than one `class` attribute, the class is added to the first,
+    /// since that is the one browsers read.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div);
+    /// element.add_class("card");
+    /// element.add_class("wide");
+    /// assert_eq!(element.to_html_string(), r#"<div class="card wide"/>"#);
+    /// ```
+    pub fn add_class(&mut self, class: impl ToString) {
+        let class = class.to_string();
+        match self.attributes.iter_mut().find(|(k, _)| k == "class") {
+            Some((_, classes)) => {
+                classes.push(' ');
+                classes.push_str(&class);
+            }
+            None => self.add_attribute("class", class),
+        }
+    }
+
+    /// Consume this element and return it with the given class appended to its `class` attribute
+    ///
+    /// Unlike [`with_attribute`](HtmlElement::with_attribute), this keeps the classes which are
+    /// already set.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let output = HtmlElement::new(HtmlTag::Div)
+    ///     .with_attribute("class", "card")
+    ///     .with_attribute("id", "main")
+    ///     .with_class("wide")
+    ///     .to_html_string();
+    /// assert_eq!(output, r#"<div class="card wide" id="main"/>"#);
+    /// ```
+    pub fn with_class(mut self, class: impl ToString) -> Self {
+        self.add_class(class);
+        self
+    }
+
+    /// Add an ARIA state attribute, such as `aria-expanded`, with a `"true"` or `"false"` value
+    ///
+    /// ARIA states are not HTML boolean attributes: they must always be present with an explicit
+    /// string value. The `aria-` prefix will be added to the name if it is not already present.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div);
+    /// element.add_aria_state("expanded", false);
+    /// assert_eq!(element.to_html_string(), r#"<div aria-expanded="false"/>"#);
+    /// ```
+    pub fn add_aria_state(&mut self, name: impl AsRef<str>, state: bool) {
+        let name = name.as_ref();
+        if name.starts_with("aria-") {
+            self.add_attribute(name, state);
+        } else {
+            self.add_attribute(format!("aria-{}", name), state);
+        }
+    }
+
+    /// Consume this element and return it with the given ARIA state attribute set
+    ///
+    /// ARIA states are not HTML boolean attributes: they must always be present with an explicit
+    /// string value. The `aria-` prefix will be added to the name if it is not already present.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let output = HtmlElement::new(HtmlTag::Div)
+    ///     .with_attribute("role", "checkbox")
+    ///     .with_aria_state("checked", true)
+    ///     .with_aria_state("aria-disabled", false)
+    ///     .to_html_string();
+    /// assert_eq!(
+    ///     output,
+    ///     r#"<div role="checkbox" aria-checked="true" aria-disabled="false"/>"#
+    /// );
+    /// ```
+    pub fn with_aria_state(mut self, name: impl AsRef<str>, state: bool) -> Self {
+        self.add_aria_state(name, state);
+        self
+    }
+
+    /// Set the text direction of this element with the `dir` attribute
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div);
+    /// element.add_dir(Direction::Auto);
+    /// assert_eq!(element.to_html_string(), r#"<div dir="auto"/>"#);
+    /// ```
+    pub fn add_dir(&mut self, direction: Direction) {
+        self.add_attribute("dir", direction);
+    }
+
+    /// Consume this element and return it with its text direction set by the `dir` attribute
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let output = HtmlElement::new(HtmlTag::Div)
+    ///     .with_dir(Direction::Rtl)
+    ///     .with_paragraph("שלום")
+    ///     .to_html_string();
+    /// assert_eq!(output, r#"<div dir="rtl"><p>שלום</p></div>"#);
+    /// ```
+    pub fn with_dir(mut self, direction: Direction) -> Self {
+        self.add_dir(direction);
+        self
+    }
+
+    /// Check whether this element has the given tag and/or attribute
+    ///
+    /// Passing `None` for either filter skips that check. Attributes are compared exactly, so
+    /// `("class", "a")` will not match an element with `class="a b"`.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div).with_attribute("id", "main");
+    /// assert!(element.matches(Some(&HtmlTag::Div), None));
+    /// assert!(element.matches(None, Some(("id", "main"))));
+    /// assert!(element.matches(Some(&HtmlTag::Div), Some(("id", "main"))));
+    /// assert!(!element.matches(Some(&HtmlTag::Span), Some(("id", "main"))));
+    /// assert!(!element.matches(None, Some(("id", "other"))));
+    /// ```
+    pub fn matches(&self, tag: Option<&HtmlTag>, attr: Option<(&str, &str)>) -> bool {
+        let tag_matches = tag.map_or(true, |t| self.tag == *t);
+        let attr_matches = attr.map_or(true, |(key, value)| self.get_attribute(key) == Some(value));
+        tag_matches && attr_matches
+    }
+
+    /// Check whether this element has the given tag
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div);
+    /// assert!(element.tag_is(&HtmlTag::Div));
+    /// assert!(!element.tag_is(&HtmlTag::Span));
+    /// ```
+    pub fn tag_is(&self, tag: &HtmlTag) -> bool {
+        self.tag == *tag
+    }
+
+    /// Check whether this element has an attribute with the given name, regardless of its value
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let image = HtmlElement::new(HtmlTag::Image).with_attribute("alt", "");
+    /// assert!(image.has_attribute("alt"));
+    /// assert!(!image.has_attribute("src"));
+    /// ```
+    pub fn has_attribute(&self, key: &str) -> bool {
+        self.attributes.iter().any(|(k, _)| k == key)
+    }
+
+    /// Get the value of the attribute with the given name
+    ///
+    /// If the attribute appears more than once, the first value is returned, since that is the
+    /// one browsers read.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut link = HtmlElement::new(HtmlTag::Link).with_attribute("href", "/first");
+    /// link.push_attribute("href", "/second");
+    /// assert_eq!(link.get_attribute("href"), Some("/first"));
+    /// assert_eq!(link.get_attribute("target"), None);
+    /// ```
+    pub fn get_attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Remove every attribute with the given name, returning whether any were removed
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_attribute("class", "a")
+    ///     .with_attribute("id", "main")
+    ///     .with_attribute("class", "b");
+    ///
+    /// assert!(element.remove_attribute("class"));
+    /// assert!(!element.remove_attribute("style"));
+    /// assert_eq!(element.to_html_string(), r#"<div id="main"/>"#);
+    /// ```
+    pub fn remove_attribute(&mut self, key: &str) -> bool {
+        let before = self.attributes.len();
+        self.attributes.retain(|(k, _)| k != key);
+        self.attributes.len() != before
+    }
+
+    /// Get a map view of the attributes of this element, keyed by attribute name
+    ///
+    /// If the same attribute appears more than once, the first value wins, which matches how
+    /// browsers read repeated attributes. The ordered [`attributes`](HtmlElement::attributes)
+    /// vector is still what gets rendered.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_attribute("id", "main")
+    ///     .with_attribute("class", "wide");
+    /// element.push_attribute("id", "other");
+    /// let attributes = element.attribute_map();
+    ///
+    /// assert_eq!(attributes.get("id"), Some(&"main"));
+    /// assert_eq!(attributes.get("class"), Some(&"wide"));
+    /// assert!(!attributes.contains_key("style"));
+    /// ```
+    pub fn attribute_map(&self) -> BTreeMap<&str, &str> {
+        let mut map = BTreeMap::new();
+        for (k, v) in self.attributes.iter() {
+            map.entry(k.as_str()).or_insert(v.as_str());
+        }
+        map
+    }
+
+    /// Find the path to the first descendant of this element matching the given predicate
+    ///
+    /// The path is a list of indices into the `children` of each element along the way, starting
+    /// from this element. Descendants are searched depth-first, in document order. This element
+    /// itself is not considered.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_child("text".into())
+    ///     .with_element_child(HtmlTag::ParagraphText, |p| {
+    ///         p.with_element_child(HtmlTag::Span, |s| s.with_attribute("id", "target"))
+    ///     });
+    ///
+    /// let path = element.path_to_first(|e| e.matches(None, Some(("id", "target"))));
+    /// assert_eq!(path, Some(vec![1, 0]));
+    /// assert_eq!(element.path_to_first(|e| e.tag == HtmlTag::Table), None);
+    /// ```
+    pub fn path_to_first<P>(&self, predicate: P) -> Option<Vec<usize>>
+    where
+        P: Fn(&HtmlElement) -> bool,
+    {
+        let mut path = Vec::new();
+        if self.find_path(&predicate, &mut path) {
+            Some(path)
+        } else {
+            None
+        }
+    }
+
+    /// Count the elements nested anywhere within this element
+    ///
+    /// Only structured [`HtmlChild::Element`] children are counted; raw content is not parsed.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_element_child(HtmlTag::ParagraphText, |p| {
+    ///         p.with_child("text".into())
+    ///             .with_child(HtmlElement::new(HtmlTag::LineBreak).into())
+    ///     })
+    ///     .with_child(HtmlElement::new(HtmlTag::HorizontalRule).into());
+    ///
+    /// assert_eq!(element.descendant_count(), 3);
+    /// assert_eq!(HtmlElement::new(HtmlTag::Div).descendant_count(), 0);
+    /// ```
+    pub fn descendant_count(&self) -> usize {
+        self.children
+            .iter()
+            .filter_map(HtmlChild::as_element)
+            .map(|e| 1 + e.descendant_count())
+            .sum()
+    }
+
+    /// Get the number of levels of elements in this tree, including this element itself
+    ///
+    /// An element with no element children has a depth of 1. Only structured
+    /// [`HtmlChild::Element`] children are considered; raw content is not parsed.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_element_child(HtmlTag::ParagraphText, |p| {
+    ///         p.with_element_child(HtmlTag::Span, |s| s.with_child("deep".into()))
+    ///     })
+    ///     .with_child(HtmlElement::new(HtmlTag::HorizontalRule).into());
+    ///
+    /// assert_eq!(element.max_depth(), 3);
+    /// assert_eq!(HtmlElement::new(HtmlTag::Div).max_depth(), 1);
+    /// ```
+    pub fn max_depth(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .filter_map(HtmlChild::as_element)
+            .map(HtmlElement::max_depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Render this element using the specified [`QuoteStyle`] for attribute values
+    ///
+    /// The style applies to this element and all of its structured descendants. Content stored as
+    /// [`HtmlChild::Raw`] has already been rendered, so it is written out unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_attribute("class", "outer")
+    ///     .with_element_child(HtmlTag::ParagraphText, |p| {
+    ///         p.with_attribute("class", "inner").with_child("Text".into())
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     element.to_html_string_with_quotes(QuoteStyle::Single),
+    ///     "<div class='outer'><p class='inner'>Text</p></div>"
+    /// );
+    /// assert_eq!(
+    ///     element.to_html_string_with_quotes(QuoteStyle::Double),
+    ///     element.to_html_string()
+    /// );
+    /// ```
+    pub fn to_html_string_with_quotes(&self, quotes: QuoteStyle) -> String {
+        Quoted(self, quotes).to_string()
+    }
+
+    /// Render this element, passing the output of each element in the tree through `hook`
+    ///
+    /// The hook is called once for every structured element, innermost first, with the element
+    /// and its rendered HTML (which already includes the hooked output of its children). The
+    /// string it returns is used in place of that element's output. This allows small tweaks to
+    /// the serialization of particular tags without reimplementing rendering. Content stored as
+    /// [`HtmlChild::Raw`] is not passed to the hook.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::ParagraphText)
+    ///     .with_child("Line 1".into())
+    ///     .with_child(HtmlElement::new(HtmlTag::LineBreak).into())
+    ///     .with_child("Line 2".into());
+    ///
+    /// let output = element.render_with(|e, html| match e.tag {
+    ///     HtmlTag::LineBreak => html + "\n",
+    ///     _ => html,
+    /// });
+    /// assert_eq!(output, "<p>Line 1<br/>\nLine 2</p>");
+    /// ```
+    pub fn render_with<F>(&self, hook: F) -> String
+    where
+        F: Fn(&HtmlElement, String) -> String,
+    {
+        self.render_hooked(&hook)
+    }
+
+    /// Recursive helper for [`render_with`](HtmlElement::render_with)
+    fn render_hooked(&self, hook: &dyn Fn(&HtmlElement, String) -> String) -> String {
+        let shallow = HtmlElement {
+            tag: self.tag.clone(),
+            attributes: self.attributes.clone(),
+            children: self
+                .children
+                .iter()
+                .map(|child| match child {
+                    HtmlChild::Element(e) => HtmlChild::Raw(e.render_hooked(hook)),
+                    other => other.clone(),
+                })
+                .collect(),
+        };
+        hook(self, shallow.to_html_string())
+    }
+
+    /// Recursive helper for [`path_to_first`](HtmlElement::path_to_first)
+    fn find_path<P>(&self, predicate: &P, path: &mut Vec<usize>) -> bool
+    where
+        P: Fn(&HtmlElement) -> bool,
+    {
+        for (i, child) in self.children.iter().enumerate() {
+            if let HtmlChild::Element(e) = child {
+                path.push(i);
+                if predicate(e) || e.find_path(predicate, path) {
+                    return true;
+                }
+                path.pop();
+            }
+        }
+        false
+    }
+
+    /// Get a displayable view of the opening tag of this element, such as `<div id="main">`
+    pub(crate) fn open_tag(&self) -> OpenTag<'_> {
+        OpenTag(self)
+    }
+
+    fn write_element(&self, f: &mut Formatter<'_>, quotes: QuoteStyle) -> fmt::Result {
+        if self.children.is_empty() && !requires_closing_tag(&self.tag) {
+            write!(f, "<{}", self.tag)?;
+            self.write_attributes(f, quotes)?;
+            write!(f, "/>")
+        } else {
+            write!(f, "<{}", self.tag,)?;
+            self.write_attributes(f, quotes)?;
+            write!(f, ">")?;
+            self.write_children(f, quotes)?;
+            write!(f, "</{}>", self.tag)
+        }
+    }
+
+    fn write_attributes(&self, f: &mut Formatter<'_>, quotes: QuoteStyle) -> fmt::Result {
         for (k, v) in self.attributes.iter() {
-            write!(f, r#" {}="{}""#, k, v)?;
+            quotes.write_attribute(f, k, v)?;
         }
         Ok(())
     }
 
-    fn write_children(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    fn write_children(&self, f: &mut Formatter<'_>, quotes: QuoteStyle) -> fmt::Result {
         for child in self.children.iter() {
-            write!(f, "{}", child)?;
+            match child {
+                HtmlChild::Element(e) => e.write_element(f, quotes)?,
+                HtmlChild::Raw(r) => write!(f, "{}", r)?,
+                HtmlChild::Text(t) => write!(f, "{}", Escaped(t))?,
+                HtmlChild::Comment(c) => write!(f, "{}", comment_html(c))?,
+            }
         }
         Ok(())
     }
@@ -2,7 +2,10 @@
 
 use std::iter::empty;
 
-use crate::{Container, Html, HtmlChild, HtmlElement, HtmlTag, Table};
+use crate::{
+    escape_html, Article, Container, ContainerType, Details, Direction, Html, HtmlChild,
+    HtmlElement, HtmlTag, Iframe, Table,
+};
 
 /// An HTML element that can contain other HTML elements
 ///
@@ -32,6 +35,20 @@ use crate::{Container, Html, HtmlChild, HtmlElement, HtmlTag, Table};
 /// assert_eq!(content, r#"<div><p id="paragraph" class="action">123</p></div>"#)
 /// ```
 ///
+/// Text passed to methods such as `with_paragraph` is inserted as-is, so it may contain inline
+/// HTML. When rendering untrusted data, use the `_text` variants, such as
+/// [`with_paragraph_text`](HtmlContainer::with_paragraph_text), which escape the text with
+/// [`escape_html`] first.
+///
+/// ```
+/// # use build_html::*;
+/// let content = HtmlElement::new(HtmlTag::Div)
+///     .with_paragraph("<b>Bold</b>")
+///     .with_paragraph_text("<b>Not bold</b>")
+///     .to_html_string();
+/// assert_eq!(content, "<div><p><b>Bold</b></p><p>&lt;b&gt;Not bold&lt;/b&gt;</p></div>")
+/// ```
+///
 /// There are two different ways of interacting with `HtmlContainer` objects which will suit
 /// different use cases. The first is using the *with* API, which consumes the calling container.
 /// Because the calling container is consumed and returned, it can be chained effectively. This
@@ -159,6 +176,123 @@ pub trait HtmlContainer: Html + Sized {
         self
     }
 
+    /// Adds the specified [`HtmlElement`] to this container
+    ///
+    /// Containers which hold a tree of elements, such as [`HtmlElement`] and
+    /// [`HtmlPage`](crate::HtmlPage), keep the element as an [`HtmlChild::Element`] so that it can
+    /// still be inspected and edited after it is added. All of the helper methods of this trait
+    /// which build an element, such as [`add_paragraph`](HtmlContainer::add_paragraph), add it
+    /// this way. By default, the element is rendered and added with
+    /// [`add_html`](HtmlContainer::add_html), which stores it as [`HtmlChild::Raw`] text.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_element(HtmlElement::new(HtmlTag::Div).with_attribute("id", "main"));
+    /// page.add_paragraph("Text");
+    ///
+    /// let main = page.body().children[0].as_element().unwrap();
+    /// assert_eq!(main.get_attribute("id"), Some("main"));
+    /// assert!(page.body().children[1].as_element().is_some());
+    /// ```
+    fn add_element(&mut self, element: HtmlElement) {
+        self.add_html(element);
+    }
+
+    /// Consumes the container, returning it with the specified [`HtmlElement`] added to it
+    ///
+    /// See [`add_element`](HtmlContainer::add_element) for how the element is stored.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_element(HtmlElement::new_with_text(HtmlTag::Span, "inner"));
+    ///
+    /// assert_eq!(element.to_html_string(), "<div><span>inner</span></div>");
+    /// assert!(element.children[0].as_element().is_some());
+    /// ```
+    #[inline]
+    fn with_element(mut self, element: HtmlElement) -> Self {
+        self.add_element(element);
+        self
+    }
+
+    /// Adds the specified HTML element to this container only if `condition` is true
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_html_if(false, "<p>hidden</p>");
+    /// content.add_html_if(true, "<p>shown</p>");
+    /// assert_eq!(content.to_html_string(), "<div><p>shown</p></div>");
+    /// ```
+    fn add_html_if<H: Html>(&mut self, condition: bool, html: H) {
+        if condition {
+            self.add_html(html);
+        }
+    }
+
+    /// Consumes the container, returning it with the specified HTML element added to it only if
+    /// `condition` is true
+    ///
+    /// This allows conditional content to be added without breaking up a chain of `with_` calls.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let logged_in = false;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_paragraph("Welcome!")
+    ///     .with_html_if(logged_in, HtmlElement::new(HtmlTag::Link).with_child("Log out".into()))
+    ///     .with_html_if(!logged_in, HtmlElement::new(HtmlTag::Link).with_child("Log in".into()))
+    ///     .to_html_string();
+    /// assert_eq!(content, "<div><p>Welcome!</p><a>Log in</a></div>");
+    /// ```
+    fn with_html_if<H: Html>(mut self, condition: bool, html: H) -> Self {
+        self.add_html_if(condition, html);
+        self
+    }
+
+    /// Adds the specified HTML element to this container if it is present
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_html_if_some(None::<&str>);
+    /// content.add_html_if_some(Some("<p>shown</p>"));
+    /// assert_eq!(content.to_html_string(), "<div><p>shown</p></div>");
+    /// ```
+    fn add_html_if_some<H: Html>(&mut self, html: Option<H>) {
+        if let Some(html) = html {
+            self.add_html(html);
+        }
+    }
+
+    /// Consumes the container, returning it with the specified HTML element added to it if it is
+    /// present
+    ///
+    /// This allows optional content to be added without breaking up a chain of `with_` calls.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let subtitle: Option<&str> = None;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_header(1, "Title")
+    ///     .with_html_if_some(subtitle)
+    ///     .with_html_if_some(Some(HtmlElement::new(HtmlTag::HorizontalRule)))
+    ///     .to_html_string();
+    /// assert_eq!(content, "<div><h1>Title</h1><hr/></div>");
+    /// ```
+    fn with_html_if_some<H: Html>(mut self, html: Option<H>) -> Self {
+        self.add_html_if_some(html);
+        self
+    }
+
     /// Add the container to this HTML Container
     ///
     /// # Example
@@ -170,7 +304,7 @@ pub trait HtmlContainer: Html + Sized {
     /// ```
     #[inline]
     fn add_container(&mut self, container: Container) {
-        self.add_html(container)
+        self.add_element(container.0)
     }
 
     /// Nest the specified container within this container
@@ -196,6 +330,178 @@ pub trait HtmlContainer: Html + Sized {
         self.with_html(container)
     }
 
+    /// Add a `<ul>` of key-value pairs, each rendered as `<li><strong>key</strong>: value</li>`
+    ///
+    /// This suits short metadata lists. For a semantic list of terms and descriptions, use a
+    /// description list instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_unordered_list_pairs([("Author", "Joe"), ("Status", "Draft")]);
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         "<div><ul><li><strong>Author</strong>: Joe</li>",
+    ///         "<li><strong>Status</strong>: Draft</li></ul></div>"
+    ///     )
+    /// );
+    /// ```
+    fn add_unordered_list_pairs<I, K, V>(&mut self, pairs: I)
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        let list = pairs.into_iter().fold(
+            Container::new(ContainerType::UnorderedList),
+            |list, (k, v)| {
+                list.with_html(format!(
+                    "<strong>{}</strong>: {}",
+                    k.to_string(),
+                    v.to_string()
+                ))
+            },
+        );
+        self.add_container(list);
+    }
+
+    /// Add a `<ul>` of key-value pairs, each rendered as `<li><strong>key</strong>: value</li>`
+    ///
+    /// This suits short metadata lists. For a semantic list of terms and descriptions, use a
+    /// description list instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// # use std::collections::BTreeMap;
+    /// let metadata = BTreeMap::from([("Pages", 12), ("Words", 3400)]);
+    /// let content = Container::default()
+    ///     .with_unordered_list_pairs(metadata)
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         "<div><ul><li><strong>Pages</strong>: 12</li>",
+    ///         "<li><strong>Words</strong>: 3400</li></ul></div>"
+    ///     )
+    /// );
+    /// ```
+    fn with_unordered_list_pairs<I, K, V>(mut self, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        self.add_unordered_list_pairs(pairs);
+        self
+    }
+
+    /// Add the specified [`Article`] to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Main);
+    /// content.add_article(Article::new("Title").with_paragraph("Text"));
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     "<main><article><header><h1>Title</h1></header><p>Text</p></article></main>"
+    /// );
+    /// ```
+    #[inline]
+    fn add_article(&mut self, article: Article) {
+        self.add_element(article.into())
+    }
+
+    /// Nest the specified [`Article`] within this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let posts = [("First", "2024-01-01"), ("Second", "2024-02-01")];
+    /// let content = posts
+    ///     .iter()
+    ///     .fold(HtmlElement::new(HtmlTag::Main), |main, (title, date)| {
+    ///         main.with_article(Article::new(title).with_published(date, date))
+    ///     })
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         "<main>",
+    ///         r#"<article><header><h1>First</h1><time datetime="2024-01-01">2024-01-01</time>"#,
+    ///         "</header></article>",
+    ///         r#"<article><header><h1>Second</h1><time datetime="2024-02-01">2024-02-01</time>"#,
+    ///         "</header></article>",
+    ///         "</main>"
+    ///     )
+    /// );
+    /// ```
+    #[inline]
+    fn with_article(self, article: Article) -> Self {
+        self.with_html(article)
+    }
+
+    /// Add a `<div>` with the specified attributes, filled in by the provided closure
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Main);
+    /// content.add_div([("class", "card")], |div| div.with_paragraph("Inside"));
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<main><div class="card"><p>Inside</p></div></main>"#
+    /// );
+    /// ```
+    fn add_div<A, S, F>(&mut self, attr: A, build: F)
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+        F: FnOnce(Container) -> Container,
+    {
+        self.add_container(build(Container::default().with_attributes(attr)));
+    }
+
+    /// Nest a `<div>` with the specified attributes, filled in by the provided closure
+    ///
+    /// This allows deeply nested layouts to be written without building each inner
+    /// [`Container`] separately.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = Container::new(ContainerType::Main)
+    ///     .with_div([("class", "row")], |row| {
+    ///         row.with_div([("class", "col")], |col| col.with_paragraph("Left"))
+    ///             .with_div([("class", "col")], |col| col.with_paragraph("Right"))
+    ///     })
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         r#"<main><div class="row">"#,
+    ///         r#"<div class="col"><p>Left</p></div>"#,
+    ///         r#"<div class="col"><p>Right</p></div>"#,
+    ///         "</div></main>"
+    ///     )
+    /// );
+    /// ```
+    fn with_div<A, S, F>(mut self, attr: A, build: F) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+        F: FnOnce(Container) -> Container,
+    {
+        self.add_div(attr, build);
+        self
+    }
+
     /// Add the specified `Table` to this container
     ///
     /// # Example
@@ -283,6 +589,41 @@ pub trait HtmlContainer: Html + Sized {
         self.with_header_attr(level, text, empty::<(&str, &str)>())
     }
 
+    /// Adds a header tag with the designated level to this container, escaping the text
+    ///
+    /// Unlike [`add_header`](HtmlContainer::add_header), the text is escaped with [`escape_html`],
+    /// so it is safe to use with untrusted input.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_header_text(2, "Q&A <FAQ>");
+    /// assert_eq!(content.to_html_string(), "<div><h2>Q&amp;A &lt;FAQ&gt;</h2></div>");
+    /// ```
+    fn add_header_text(&mut self, level: u8, text: impl ToString) {
+        self.add_header(level, escape_html(&text.to_string()));
+    }
+
+    /// Adds a header tag with the designated level to this container, escaping the text
+    ///
+    /// Unlike [`with_header`](HtmlContainer::with_header), the text is escaped with
+    /// [`escape_html`], so it is safe to use with untrusted input.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_header_text(1, "<Untitled>")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(content, "<div><h1>&lt;Untitled&gt;</h1></div>");
+    /// ```
+    fn with_header_text(mut self, level: u8, text: impl ToString) -> Self {
+        self.add_header_text(level, text);
+        self
+    }
+
     /// Adds a header tag with the designated level and attributes to this container.
     ///
     /// # Example
@@ -297,22 +638,13 @@ pub trait HtmlContainer: Html + Sized {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        let tag = match level {
-            1 => HtmlTag::Heading1,
-            2 => HtmlTag::Heading2,
-            3 => HtmlTag::Heading3,
-            4 => HtmlTag::Heading4,
-            5 => HtmlTag::Heading5,
-            6 => HtmlTag::Heading6,
-            _ => panic!("'{}' is not a valid html heading level", level),
-        };
-
-        let mut element = HtmlElement::new(tag).with_child(HtmlChild::Raw(text.to_string()));
+        let mut element =
+            HtmlElement::new(heading_tag(level)).with_child(HtmlChild::Raw(text.to_string()));
         for (k, v) in attr {
             element.add_attribute(k, v)
         }
 
-        self.add_html(element);
+        self.add_element(element);
     }
 
     /// Adds a header tag with the designated level and attributes to this container.
@@ -335,126 +667,782 @@ pub trait HtmlContainer: Html + Sized {
         self
     }
 
-    /// Adds an `<img>` tag to this container
+    /// Adds a header tag with the designated level, wrapping the specified HTML content
+    ///
+    /// Unlike [`add_header`](HtmlContainer::add_header), this accepts any [`Html`], so the header
+    /// can contain inline elements such as links.
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
     /// let mut content = HtmlElement::new(HtmlTag::Div);
-    /// content.add_image("myimage.png", "a test image");
-    /// assert_eq!(
-    ///     content.to_html_string(),
-    ///     r#"<div><img src="myimage.png" alt="a test image"/></div>"#
-    /// );
+    /// content.add_header_html(2, HtmlElement::new_with_text(HtmlTag::Span, "Header Text"));
+    /// assert_eq!(content.to_html_string(), "<div><h2><span>Header Text</span></h2></div>");
     /// ```
-    fn add_image(&mut self, src: impl ToString, alt: impl ToString) {
-        self.add_image_attr(src, alt, empty::<(&str, &str)>());
+    fn add_header_html<H: Html>(&mut self, level: u8, content: H) {
+        self.add_element(HtmlElement::new(heading_tag(level)).with_html(content));
     }
 
-    /// Adds an `<img>` tag to this container
+    /// Adds a header tag with the designated level, wrapping the specified HTML content
+    ///
+    /// Unlike [`with_header`](HtmlContainer::with_header), this accepts any [`Html`], so the
+    /// header can contain inline elements such as links. Headings may only contain phrasing
+    /// content, so block elements such as `<div>` do not belong here.
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
     /// let content = HtmlElement::new(HtmlTag::Div)
-    ///     .with_image("myimage.png", "a test image")
+    ///     .with_header_html(1, "Using <code>build_html</code> <em>today</em>")
     ///     .to_html_string();
     ///
-    /// assert_eq!(content, r#"<div><img src="myimage.png" alt="a test image"/></div>"#);
+    /// assert_eq!(
+    ///     content,
+    ///     "<div><h1>Using <code>build_html</code> <em>today</em></h1></div>"
+    /// );
     /// ```
-    fn with_image(self, src: impl ToString, alt: impl ToString) -> Self {
-        self.with_image_attr(src, alt, empty::<(&str, &str)>())
+    fn with_header_html<H: Html>(mut self, level: u8, content: H) -> Self {
+        self.add_header_html(level, content);
+        self
     }
 
-    /// Adds an `<img>` tag with the specified attributes to this container
+    /// Adds an `<hgroup>` pairing a header of the designated level with a subheading paragraph
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
-    /// # use std::collections::BTreeMap;
-    /// let mut attrs = BTreeMap::new();
-    /// attrs.insert("id", "sample-image");
-    /// let mut content = HtmlElement::new(HtmlTag::Div);
-    /// content.add_image_attr("myimage.png", "a test image", attrs);
-    ///
+    /// let mut content = HtmlElement::new(HtmlTag::Header);
+    /// content.add_heading_group(1, "My Blog", "Thoughts and musings");
     /// assert_eq!(
     ///     content.to_html_string(),
-    ///     r#"<div><img src="myimage.png" alt="a test image" id="sample-image"/></div>"#
+    ///     "<header><hgroup><h1>My Blog</h1><p>Thoughts and musings</p></hgroup></header>"
     /// );
     /// ```
-    fn add_image_attr<A, S>(&mut self, src: impl ToString, alt: impl ToString, attr: A)
-    where
-        A: IntoIterator<Item = (S, S)>,
-        S: ToString,
-    {
-        let mut element = HtmlElement::new(HtmlTag::Image)
-            .with_attribute("src", src)
-            .with_attribute("alt", alt);
-        for (k, v) in attr {
-            element.add_attribute(k, v);
-        }
-
-        self.add_html(element);
+    fn add_heading_group(&mut self, level: u8, heading: impl ToString, subheading: impl ToString) {
+        self.add_element(
+            HtmlElement::new(HtmlTag::HeadingGroup)
+                .with_header(level, heading)
+                .with_paragraph(subheading),
+        );
     }
 
-    /// Adds an `<img>` tag with the specified attributes to this container
+    /// Adds an `<hgroup>` pairing a header of the designated level with a subheading paragraph
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
-    /// # use std::collections::BTreeMap;
-    /// let mut attrs = BTreeMap::new();
-    /// attrs.insert("id", "sample-image");
-    /// let content = HtmlElement::new(HtmlTag::Div)
-    ///     .with_image_attr("myimage.png", "a test image", attrs)
+    /// let content = HtmlElement::new(HtmlTag::Article)
+    ///     .with_heading_group(2, "Release Notes", "Version 2.0")
     ///     .to_html_string();
     ///
     /// assert_eq!(
     ///     content,
-    ///     r#"<div><img src="myimage.png" alt="a test image" id="sample-image"/></div>"#
+    ///     "<article><hgroup><h2>Release Notes</h2><p>Version 2.0</p></hgroup></article>"
     /// );
     /// ```
-    fn with_image_attr<A, S>(mut self, src: impl ToString, alt: impl ToString, attr: A) -> Self
-    where
-        A: IntoIterator<Item = (S, S)>,
-        S: ToString,
-    {
-        self.add_image_attr(src, alt, attr);
+    fn with_heading_group(
+        mut self,
+        level: u8,
+        heading: impl ToString,
+        subheading: impl ToString,
+    ) -> Self {
+        self.add_heading_group(level, heading, subheading);
         self
     }
 
-    /// Adds an `<a>` tag to this container
+    /// Adds a `<section>` containing a header with the designated level, followed by the
+    /// specified content
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
-    /// let mut content = HtmlElement::new(HtmlTag::Div);
-    /// content.add_link("https://rust-lang.org/", "Rust Homepage");
-    ///
+    /// let mut content = HtmlElement::new(HtmlTag::Main);
+    /// content.add_titled_section(2, "Overview", "<p>Some text</p>");
     /// assert_eq!(
     ///     content.to_html_string(),
-    ///     r#"<div><a href="https://rust-lang.org/">Rust Homepage</a></div>"#
+    ///     "<main><section><h2>Overview</h2><p>Some text</p></section></main>"
     /// );
     /// ```
-    fn add_link(&mut self, href: impl ToString, text: impl ToString) {
-        self.add_link_attr(href, text, empty::<(&str, &str)>());
+    fn add_titled_section<H: Html>(&mut self, level: u8, title: impl ToString, content: H) {
+        self.add_element(
+            HtmlElement::new(HtmlTag::Section)
+                .with_header(level, title)
+                .with_html(content),
+        );
     }
 
-    /// Adds an `<a>` tag to this container
+    /// Adds a `<section>` containing a header with the designated level, followed by the
+    /// specified content
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
-    /// let content = HtmlElement::new(HtmlTag::Div)
-    ///     .with_link("https://rust-lang.org/", "Rust Homepage")
+    /// let content = HtmlElement::new(HtmlTag::Main)
+    ///     .with_titled_section(
+    ///         2,
+    ///         "Overview",
+    ///         Container::default().with_paragraph("Some text"),
+    ///     )
     ///     .to_html_string();
     ///
-    /// assert_eq!(content, r#"<div><a href="https://rust-lang.org/">Rust Homepage</a></div>"#)
+    /// assert_eq!(
+    ///     content,
+    ///     "<main><section><h2>Overview</h2><div><p>Some text</p></div></section></main>"
+    /// );
     /// ```
-    fn with_link(self, href: impl ToString, text: impl ToString) -> Self {
+    fn with_titled_section<H: Html>(mut self, level: u8, title: impl ToString, content: H) -> Self {
+        self.add_titled_section(level, title, content);
+        self
+    }
+
+    /// Adds an `<img>` tag to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_image("myimage.png", "a test image");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><img src="myimage.png" alt="a test image"/></div>"#
+    /// );
+    /// ```
+    fn add_image(&mut self, src: impl ToString, alt: impl ToString) {
+        self.add_image_attr(src, alt, empty::<(&str, &str)>());
+    }
+
+    /// Adds an `<img>` tag to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_image("myimage.png", "a test image")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(content, r#"<div><img src="myimage.png" alt="a test image"/></div>"#);
+    /// ```
+    fn with_image(self, src: impl ToString, alt: impl ToString) -> Self {
+        self.with_image_attr(src, alt, empty::<(&str, &str)>())
+    }
+
+    /// Adds an `<img>` tag with the specified attributes to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// # use std::collections::BTreeMap;
+    /// let mut attrs = BTreeMap::new();
+    /// attrs.insert("id", "sample-image");
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_image_attr("myimage.png", "a test image", attrs);
+    ///
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><img src="myimage.png" alt="a test image" id="sample-image"/></div>"#
+    /// );
+    /// ```
+    fn add_image_attr<A, S>(&mut self, src: impl ToString, alt: impl ToString, attr: A)
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        let mut element = HtmlElement::new(HtmlTag::Image)
+            .with_attribute("src", src)
+            .with_attribute("alt", alt);
+        for (k, v) in attr {
+            element.add_attribute(k, v);
+        }
+
+        self.add_element(element);
+    }
+
+    /// Adds an `<img>` tag with the specified attributes to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// # use std::collections::BTreeMap;
+    /// let mut attrs = BTreeMap::new();
+    /// attrs.insert("id", "sample-image");
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_image_attr("myimage.png", "a test image", attrs)
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><img src="myimage.png" alt="a test image" id="sample-image"/></div>"#
+    /// );
+    /// ```
+    fn with_image_attr<A, S>(mut self, src: impl ToString, alt: impl ToString, attr: A) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_image_attr(src, alt, attr);
+        self
+    }
+
+    /// Adds a responsive `<img>` tag with `srcset` and `sizes` attributes to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_image_srcset("small.png", "a test image", "large.png 2x", "50vw");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         r#"<div><img src="small.png" alt="a test image" "#,
+    ///         r#"srcset="large.png 2x" sizes="50vw"/></div>"#
+    ///     )
+    /// );
+    /// ```
+    fn add_image_srcset(
+        &mut self,
+        src: impl ToString,
+        alt: impl ToString,
+        srcset: impl ToString,
+        sizes: impl ToString,
+    ) {
+        self.add_image_attr(
+            src,
+            alt,
+            [
+                ("srcset", srcset.to_string().as_str()),
+                ("sizes", sizes.to_string().as_str()),
+            ],
+        );
+    }
+
+    /// Adds a responsive `<img>` tag with `srcset` and `sizes` attributes to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_image_srcset(
+    ///         "photo-480.jpg",
+    ///         "a photo",
+    ///         "photo-480.jpg 480w, photo-800.jpg 800w",
+    ///         "(max-width: 600px) 480px, 800px",
+    ///     )
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         r#"<div><img src="photo-480.jpg" alt="a photo" "#,
+    ///         r#"srcset="photo-480.jpg 480w, photo-800.jpg 800w" "#,
+    ///         r#"sizes="(max-width: 600px) 480px, 800px"/></div>"#
+    ///     )
+    /// );
+    /// ```
+    fn with_image_srcset(
+        mut self,
+        src: impl ToString,
+        alt: impl ToString,
+        srcset: impl ToString,
+        sizes: impl ToString,
+    ) -> Self {
+        self.add_image_srcset(src, alt, srcset, sizes);
+        self
+    }
+
+    /// Adds a purely decorative `<img>` tag, which screen readers will skip
+    ///
+    /// The image is given an empty `alt` attribute and `role="presentation"`. Images that convey
+    /// information should use [`add_image`](HtmlContainer::add_image) with a meaningful description
+    /// instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_decorative_image("divider.png");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><img src="divider.png" alt="" role="presentation"/></div>"#
+    /// );
+    /// ```
+    fn add_decorative_image(&mut self, src: impl ToString) {
+        self.add_image_attr(src, "", [("role", "presentation")]);
+    }
+
+    /// Adds a purely decorative `<img>` tag, which screen readers will skip
+    ///
+    /// The image is given an empty `alt` attribute and `role="presentation"`. Images that convey
+    /// information should use [`with_image`](HtmlContainer::with_image) with a meaningful
+    /// description instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_decorative_image("flourish.svg")
+    ///     .with_paragraph("Text")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><img src="flourish.svg" alt="" role="presentation"/><p>Text</p></div>"#
+    /// );
+    /// ```
+    fn with_decorative_image(mut self, src: impl ToString) -> Self {
+        self.add_decorative_image(src);
+        self
+    }
+
+    /// Adds a captioned image, as a `<figure>` containing an `<img>` and a `<figcaption>`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_figure_image("cat.jpg", "A sleeping cat", "Figure 1: <em>Felix</em>");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         r#"<div><figure><img src="cat.jpg" alt="A sleeping cat"/>"#,
+    ///         "<figcaption>Figure 1: <em>Felix</em></figcaption></figure></div>"
+    ///     )
+    /// );
+    /// ```
+    fn add_figure_image(&mut self, src: impl ToString, alt: impl ToString, caption: impl Html) {
+        self.add_element(
+            HtmlElement::new(HtmlTag::Figure)
+                .with_image(src, alt)
+                .with_child(
+                    HtmlElement::new(HtmlTag::Figcaption)
+                        .with_html(caption)
+                        .into(),
+                ),
+        );
+    }
+
+    /// Adds a captioned image, as a `<figure>` containing an `<img>` and a `<figcaption>`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = Container::new(ContainerType::Article)
+    ///     .with_figure_image("chart.png", "Sales by month", "Sales grew in spring")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         r#"<article><figure><img src="chart.png" alt="Sales by month"/>"#,
+    ///         "<figcaption>Sales grew in spring</figcaption></figure></article>"
+    ///     )
+    /// );
+    /// ```
+    fn with_figure_image(
+        mut self,
+        src: impl ToString,
+        alt: impl ToString,
+        caption: impl Html,
+    ) -> Self {
+        self.add_figure_image(src, alt, caption);
+        self
+    }
+
+    /// Adds a lazily loaded `<iframe>` embedding the specified URL to this container
+    ///
+    /// For more control over the frame, such as its permissions or referrer policy, build an
+    /// [`Iframe`] and add it with [`add_html`](HtmlContainer::add_html).
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_lazy_iframe("https://example.com/map", "Office location");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         r#"<div><iframe src="https://example.com/map" title="Office location" "#,
+    ///         r#"loading="lazy"></iframe></div>"#
+    ///     )
+    /// );
+    /// ```
+    fn add_lazy_iframe(&mut self, src: impl ToString, title: impl ToString) {
+        self.add_element(Iframe::new(src).with_title(title).with_lazy().into());
+    }
+
+    /// Adds a lazily loaded `<iframe>` embedding the specified URL to this container
+    ///
+    /// For more control over the frame, such as its permissions or referrer policy, build an
+    /// [`Iframe`] and add it with [`with_html`](HtmlContainer::with_html).
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_lazy_iframe("https://example.com/map", "Office location")
+    ///     .with_html(Iframe::new("video.html").with_allow(["fullscreen"]))
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         r#"<div><iframe src="https://example.com/map" title="Office location" "#,
+    ///         r#"loading="lazy"></iframe>"#,
+    ///         r#"<iframe src="video.html" allow="fullscreen"></iframe></div>"#
+    ///     )
+    /// );
+    /// ```
+    fn with_lazy_iframe(mut self, src: impl ToString, title: impl ToString) -> Self {
+        self.add_lazy_iframe(src, title);
+        self
+    }
+
+    /// Adds a `<bdi>` element, which isolates text whose direction may differ from its surroundings
+    ///
+    /// This is useful for inserting user-provided text, such as names, whose direction is unknown.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::ListElement);
+    /// content.add_bdi("إيان");
+    /// content.add_html(": 3 posts");
+    /// assert_eq!(content.to_html_string(), "<li><bdi>إيان</bdi>: 3 posts</li>");
+    /// ```
+    fn add_bdi(&mut self, text: impl ToString) {
+        self.add_element(HtmlElement::new_with_text(HtmlTag::Bdi, text));
+    }
+
+    /// Adds a `<bdi>` element, which isolates text whose direction may differ from its surroundings
+    ///
+    /// This is useful for inserting user-provided text, such as names, whose direction is unknown.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::ParagraphText)
+    ///     .with_html("User ")
+    ///     .with_bdi("إيان")
+    ///     .to_html_string();
+    /// assert_eq!(content, "<p>User <bdi>إيان</bdi></p>");
+    /// ```
+    fn with_bdi(mut self, text: impl ToString) -> Self {
+        self.add_bdi(text);
+        self
+    }
+
+    /// Adds a `<bdo>` element, which forces the contained text to render in the given direction
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::ParagraphText);
+    /// content.add_bdo("Hello", Direction::Rtl);
+    /// assert_eq!(content.to_html_string(), r#"<p><bdo dir="rtl">Hello</bdo></p>"#);
+    /// ```
+    fn add_bdo(&mut self, text: impl ToString, direction: Direction) {
+        self.add_element(HtmlElement::new_with_text(HtmlTag::Bdo, text).with_dir(direction));
+    }
+
+    /// Adds a `<bdo>` element, which forces the contained text to render in the given direction
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::ParagraphText)
+    ///     .with_bdo("Hello", Direction::Ltr)
+    ///     .to_html_string();
+    /// assert_eq!(content, r#"<p><bdo dir="ltr">Hello</bdo></p>"#);
+    /// ```
+    fn with_bdo(mut self, text: impl ToString, direction: Direction) -> Self {
+        self.add_bdo(text, direction);
+        self
+    }
+
+    /// Adds a collapsible `<details>` section which can be linked to with the specified id
+    ///
+    /// The id is placed on the `<details>` element itself rather than the `<summary>`, so links to
+    /// `#id` scroll to the whole section. For more control, build a [`Details`] directly.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_collapsible_section("section-3", "Installation", "Run <code>cargo add</code>");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         r#"<div><details id="section-3"><summary>Installation</summary>"#,
+    ///         "Run <code>cargo add</code></details></div>"
+    ///     )
+    /// );
+    /// ```
+    fn add_collapsible_section(&mut self, id: impl ToString, title: impl Html, content: impl Html) {
+        self.add_element(
+            Details::new()
+                .with_attributes([("id", id.to_string().as_str())])
+                .with_summary(title)
+                .with_html(content)
+                .into(),
+        );
+    }
+
+    /// Adds a collapsible `<details>` section which can be linked to with the specified id
+    ///
+    /// The id is placed on the `<details>` element itself rather than the `<summary>`, so links to
+    /// `#id` scroll to the whole section. For more control, build a [`Details`] directly.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = Container::default()
+    ///     .with_collapsible_section("usage", "Usage", Container::default().with_paragraph("Text"))
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><details id="usage"><summary>Usage</summary><div><p>Text</p></div></details></div>"#
+    /// );
+    /// ```
+    fn with_collapsible_section(
+        mut self,
+        id: impl ToString,
+        title: impl Html,
+        content: impl Html,
+    ) -> Self {
+        self.add_collapsible_section(id, title, content);
+        self
+    }
+
+    /// Adds an `<img>` tag which the browser will load lazily and decode asynchronously
+    ///
+    /// This sets `loading="lazy"` and `decoding="async"`, which are appropriate for images that
+    /// start out below the fold.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_image_lazy("myimage.png", "a test image");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         r#"<div><img src="myimage.png" alt="a test image" "#,
+    ///         r#"loading="lazy" decoding="async"/></div>"#
+    ///     )
+    /// );
+    /// ```
+    fn add_image_lazy(&mut self, src: impl ToString, alt: impl ToString) {
+        self.add_image_attr(src, alt, [("loading", "lazy"), ("decoding", "async")]);
+    }
+
+    /// Adds an `<img>` tag which the browser will load lazily and decode asynchronously
+    ///
+    /// This sets `loading="lazy"` and `decoding="async"`, which are appropriate for images that
+    /// start out below the fold.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_image("hero.png", "above the fold")
+    ///     .with_image_lazy("footer.png", "below the fold")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         r#"<div><img src="hero.png" alt="above the fold"/>"#,
+    ///         r#"<img src="footer.png" alt="below the fold" loading="lazy" decoding="async"/>"#,
+    ///         "</div>"
+    ///     )
+    /// );
+    /// ```
+    fn with_image_lazy(mut self, src: impl ToString, alt: impl ToString) -> Self {
+        self.add_image_lazy(src, alt);
+        self
+    }
+
+    /// Adds a `<canvas>` tag with the specified id and pixel dimensions to this container
+    ///
+    /// Since `<canvas>` is not a void element, a closing tag is always emitted.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_canvas("chart", 800, 600);
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><canvas id="chart" width="800" height="600"></canvas></div>"#
+    /// );
+    /// ```
+    fn add_canvas(&mut self, id: impl ToString, width: u32, height: u32) {
+        self.add_canvas_fallback(id, width, height, "");
+    }
+
+    /// Adds a `<canvas>` tag with the specified id and pixel dimensions to this container
+    ///
+    /// Since `<canvas>` is not a void element, a closing tag is always emitted.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_canvas("chart", 800, 600)
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><canvas id="chart" width="800" height="600"></canvas></div>"#
+    /// );
+    /// ```
+    fn with_canvas(mut self, id: impl ToString, width: u32, height: u32) -> Self {
+        self.add_canvas(id, width, height);
+        self
+    }
+
+    /// Adds a `<canvas>` tag with the specified id, pixel dimensions, and fallback content to
+    /// this container
+    ///
+    /// The fallback content is shown by browsers which cannot render the canvas, and is read by
+    /// assistive technologies.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_canvas_fallback("chart", 800, 600, "Sales by month");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><canvas id="chart" width="800" height="600">Sales by month</canvas></div>"#
+    /// );
+    /// ```
+    fn add_canvas_fallback<H: Html>(
+        &mut self,
+        id: impl ToString,
+        width: u32,
+        height: u32,
+        fallback: H,
+    ) {
+        self.add_element(
+            HtmlElement::new(HtmlTag::Canvas)
+                .with_attribute("id", id)
+                .with_attribute("width", width)
+                .with_attribute("height", height)
+                .with_child(HtmlChild::Raw(fallback.to_html_string())),
+        );
+    }
+
+    /// Adds a `<canvas>` tag with the specified id, pixel dimensions, and fallback content to
+    /// this container
+    ///
+    /// The fallback content is shown by browsers which cannot render the canvas, and is read by
+    /// assistive technologies.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_canvas_fallback(
+    ///         "chart",
+    ///         800,
+    ///         600,
+    ///         HtmlElement::new(HtmlTag::ParagraphText).with_child("Sales by month".into()),
+    ///     )
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><canvas id="chart" width="800" height="600"><p>Sales by month</p></canvas></div>"#
+    /// );
+    /// ```
+    fn with_canvas_fallback<H: Html>(
+        mut self,
+        id: impl ToString,
+        width: u32,
+        height: u32,
+        fallback: H,
+    ) -> Self {
+        self.add_canvas_fallback(id, width, height, fallback);
+        self
+    }
+
+    /// Adds an `<a>` tag to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_link("https://rust-lang.org/", "Rust Homepage");
+    ///
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><a href="https://rust-lang.org/">Rust Homepage</a></div>"#
+    /// );
+    /// ```
+    fn add_link(&mut self, href: impl ToString, text: impl ToString) {
+        self.add_link_attr(href, text, empty::<(&str, &str)>());
+    }
+
+    /// Adds an `<a>` tag to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_link("https://rust-lang.org/", "Rust Homepage")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(content, r#"<div><a href="https://rust-lang.org/">Rust Homepage</a></div>"#)
+    /// ```
+    fn with_link(self, href: impl ToString, text: impl ToString) -> Self {
         self.with_link_attr(href, text, empty::<(&str, &str)>())
     }
 
+    /// Adds an `<a>` tag to this container, escaping the link text
+    ///
+    /// Unlike [`add_link`](HtmlContainer::add_link), the text is escaped with [`escape_html`], so
+    /// it is safe to use with untrusted input. Like all attribute values, the `href` is escaped
+    /// when rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_link_text("/users/7", "<b>mallory</b>");
+    ///
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><a href="/users/7">&lt;b&gt;mallory&lt;/b&gt;</a></div>"#
+    /// );
+    /// ```
+    fn add_link_text(&mut self, href: impl ToString, text: impl ToString) {
+        self.add_link(href, escape_html(&text.to_string()));
+    }
+
+    /// Adds an `<a>` tag to this container, escaping the link text
+    ///
+    /// Unlike [`with_link`](HtmlContainer::with_link), the text is escaped with [`escape_html`],
+    /// so it is safe to use with untrusted input. Like all attribute values, the `href` is escaped
+    /// when rendered.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_link_text("/search?q=a&b", "Results for a & b")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><a href="/search?q=a&amp;b">Results for a &amp; b</a></div>"#
+    /// );
+    /// ```
+    fn with_link_text(mut self, href: impl ToString, text: impl ToString) -> Self {
+        self.add_link_text(href, text);
+        self
+    }
+
     /// Adds an `<a>` tag with the specified attributes to this container
     ///
     /// # Example
@@ -479,7 +1467,7 @@ pub trait HtmlContainer: Html + Sized {
         for (k, v) in attr {
             element.add_attribute(k, v);
         }
-        self.add_html(element);
+        self.add_element(element);
     }
 
     /// Adds an `<a>` tag with the specified attributes to this container
@@ -505,6 +1493,106 @@ pub trait HtmlContainer: Html + Sized {
         self
     }
 
+    /// Adds an `<a>` tag which opens in a new tab to this container
+    ///
+    /// The link is given `rel="noopener noreferrer"` so that the opened page cannot access this
+    /// one through `window.opener`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_link_blank("https://rust-lang.org/", "Rust Homepage");
+    ///
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         r#"<div><a href="https://rust-lang.org/" target="_blank" "#,
+    ///         r#"rel="noopener noreferrer">Rust Homepage</a></div>"#
+    ///     )
+    /// );
+    /// ```
+    fn add_link_blank(&mut self, href: impl ToString, text: impl ToString) {
+        self.add_link_attr(
+            href,
+            text,
+            [("target", "_blank"), ("rel", "noopener noreferrer")],
+        );
+    }
+
+    /// Adds an `<a>` tag which opens in a new tab to this container
+    ///
+    /// The link is given `rel="noopener noreferrer"` so that the opened page cannot access this
+    /// one through `window.opener`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_link_blank("https://rust-lang.org/", "Rust Homepage")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     concat!(
+    ///         r#"<div><a href="https://rust-lang.org/" target="_blank" "#,
+    ///         r#"rel="noopener noreferrer">Rust Homepage</a></div>"#
+    ///     )
+    /// );
+    /// ```
+    fn with_link_blank(mut self, href: impl ToString, text: impl ToString) -> Self {
+        self.add_link_blank(href, text);
+        self
+    }
+
+    /// Adds a `<time>` tag showing `display` to readers, with `datetime` as its machine-readable
+    /// `datetime` attribute
+    ///
+    /// The `datetime` value should be a valid HTML date or time string, such as an ISO 8601
+    /// timestamp. It is not validated.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_timestamp("March 1st, 2024", "2024-03-01");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><time datetime="2024-03-01">March 1st, 2024</time></div>"#
+    /// );
+    /// ```
+    fn add_timestamp(&mut self, display: impl ToString, datetime: impl ToString) {
+        self.add_element(
+            HtmlElement::new(HtmlTag::Time)
+                .with_attribute("datetime", datetime)
+                .with_child(display.to_string().into()),
+        );
+    }
+
+    /// Adds a `<time>` tag showing `display` to readers, with `datetime` as its machine-readable
+    /// `datetime` attribute
+    ///
+    /// The `datetime` value should be a valid HTML date or time string, such as an ISO 8601
+    /// timestamp. It is not validated.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::ParagraphText)
+    ///     .with_raw("Generated ")
+    ///     .with_timestamp("yesterday at noon", "2024-03-01T12:00:00Z")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<p>Generated <time datetime="2024-03-01T12:00:00Z">yesterday at noon</time></p>"#
+    /// );
+    /// ```
+    fn with_timestamp(mut self, display: impl ToString, datetime: impl ToString) -> Self {
+        self.add_timestamp(display, datetime);
+        self
+    }
+
     /// Adds a `<p>` tag element to this Container
     ///
     /// # Example
@@ -533,6 +1621,45 @@ pub trait HtmlContainer: Html + Sized {
         self.with_paragraph_attr(text, empty::<(&str, &str)>())
     }
 
+    /// Adds a `<p>` tag element to this container, escaping the text
+    ///
+    /// Unlike [`add_paragraph`](HtmlContainer::add_paragraph), the text is escaped with
+    /// [`escape_html`], so it is safe to use with untrusted input.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_paragraph_text("1 < 2 && 3 > 2");
+    /// assert_eq!(content.to_html_string(), "<div><p>1 &lt; 2 &amp;&amp; 3 &gt; 2</p></div>");
+    /// ```
+    fn add_paragraph_text(&mut self, text: impl ToString) {
+        self.add_paragraph(escape_html(&text.to_string()));
+    }
+
+    /// Adds a `<p>` tag element to this container, escaping the text
+    ///
+    /// Unlike [`with_paragraph`](HtmlContainer::with_paragraph), the text is escaped with
+    /// [`escape_html`], so it is safe to use with untrusted input.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let comment = "<script>alert('hi')</script>";
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_paragraph_text(comment)
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     "<div><p>&lt;script&gt;alert(&#39;hi&#39;)&lt;/script&gt;</p></div>"
+    /// );
+    /// ```
+    fn with_paragraph_text(mut self, text: impl ToString) -> Self {
+        self.add_paragraph_text(text);
+        self
+    }
+
     /// Adds a `<p>` tag element with the specified attributes to this Container
     ///
     /// # Example
@@ -555,7 +1682,7 @@ pub trait HtmlContainer: Html + Sized {
         for (k, v) in attr {
             element.add_attribute(k, v);
         }
-        self.add_html(element);
+        self.add_element(element);
     }
 
     /// Adds a `<p>` tag element with the specified attributes to this Container
@@ -578,6 +1705,126 @@ pub trait HtmlContainer: Html + Sized {
         self
     }
 
+    /// Adds a `<p>` tag element wrapping the specified HTML content to this container
+    ///
+    /// Unlike [`add_paragraph`](HtmlContainer::add_paragraph), this accepts any [`Html`], so the
+    /// paragraph can contain inline elements such as links.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_paragraph_html(HtmlElement::new_with_text(HtmlTag::CodeText, "main()"));
+    /// assert_eq!(content.to_html_string(), "<div><p><code>main()</code></p></div>");
+    /// ```
+    fn add_paragraph_html<H: Html>(&mut self, content: H) {
+        self.add_element(HtmlElement::new(HtmlTag::ParagraphText).with_html(content));
+    }
+
+    /// Adds a `<p>` tag element wrapping the specified HTML content to this container
+    ///
+    /// Unlike [`with_paragraph`](HtmlContainer::with_paragraph), this accepts any [`Html`], so the
+    /// paragraph can contain inline elements such as links.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let sentence = HtmlElement::new(HtmlTag::Span)
+    ///     .with_raw("See ")
+    ///     .with_link("https://rust-lang.org", "here")
+    ///     .with_raw(" for more.");
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_paragraph_html(sentence)
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><p><span>See <a href="https://rust-lang.org">here</a> for more.</span></p></div>"#
+    /// );
+    /// ```
+    fn with_paragraph_html<H: Html>(mut self, content: H) -> Self {
+        self.add_paragraph_html(content);
+        self
+    }
+
+    /// Adds a `<span>` with a single class, such as a badge or label, to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::ListElement);
+    /// content.add_html("Inbox ");
+    /// content.add_span_class("3", "badge");
+    /// assert_eq!(content.to_html_string(), r#"<li>Inbox <span class="badge">3</span></li>"#);
+    /// ```
+    fn add_span_class(&mut self, text: impl ToString, class: impl ToString) {
+        self.add_span_class_attr(text, class, empty::<(&str, &str)>());
+    }
+
+    /// Adds a `<span>` with a single class, such as a badge or label, to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::ParagraphText)
+    ///     .with_span_class("New", "badge")
+    ///     .with_html(" Release notes")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(content, r#"<p><span class="badge">New</span> Release notes</p>"#);
+    /// ```
+    fn with_span_class(self, text: impl ToString, class: impl ToString) -> Self {
+        self.with_span_class_attr(text, class, empty::<(&str, &str)>())
+    }
+
+    /// Adds a `<span>` with a class and the specified additional attributes to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_span_class_attr("Beta", "badge", [("title", "Subject to change")]);
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     r#"<div><span class="badge" title="Subject to change">Beta</span></div>"#
+    /// );
+    /// ```
+    fn add_span_class_attr<A, S>(&mut self, text: impl ToString, class: impl ToString, attr: A)
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        let element = HtmlElement::new_with_text(HtmlTag::Span, text)
+            .with_attribute("class", class)
+            .with_attributes(attr);
+        self.add_element(element);
+    }
+
+    /// Adds a `<span>` with a class and the specified additional attributes to this container
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_span_class_attr("Beta", "badge", [("id", "beta")])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(content, r#"<div><span class="badge" id="beta">Beta</span></div>"#);
+    /// ```
+    fn with_span_class_attr<A, S>(
+        mut self,
+        text: impl ToString,
+        class: impl ToString,
+        attr: A,
+    ) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_span_class_attr(text, class, attr);
+        self
+    }
+
     /// Adds a `<pre>` tag element to this container
     ///
     /// # Example
@@ -609,6 +1856,41 @@ pub trait HtmlContainer: Html + Sized {
         self.with_preformatted_attr(text, empty::<(&str, &str)>())
     }
 
+    /// Adds a `<pre>` tag element to this container, escaping the text
+    ///
+    /// Unlike [`add_preformatted`](HtmlContainer::add_preformatted), the text is escaped with
+    /// [`escape_html`], so it is safe to use with untrusted input.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_preformatted_text("Vec<String>");
+    /// assert_eq!(content.to_html_string(), "<div><pre>Vec&lt;String&gt;</pre></div>");
+    /// ```
+    fn add_preformatted_text(&mut self, text: impl ToString) {
+        self.add_preformatted(escape_html(&text.to_string()));
+    }
+
+    /// Adds a `<pre>` tag element to this container, escaping the text
+    ///
+    /// Unlike [`with_preformatted`](HtmlContainer::with_preformatted), the text is escaped with
+    /// [`escape_html`], so it is safe to use with untrusted input.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_preformatted_text("a -> b & c")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(content, "<div><pre>a -&gt; b &amp; c</pre></div>");
+    /// ```
+    fn with_preformatted_text(mut self, text: impl ToString) -> Self {
+        self.add_preformatted_text(text);
+        self
+    }
+
     /// Adds a `<pre>` tag element with the specified attributes to this container
     ///
     /// # Example
@@ -631,7 +1913,7 @@ pub trait HtmlContainer: Html + Sized {
         for (k, v) in attr {
             element.add_attribute(k, v);
         }
-        self.add_html(element);
+        self.add_element(element);
     }
 
     /// Adds a `<pre>` tag element with the specified attributes to this container
@@ -654,6 +1936,54 @@ pub trait HtmlContainer: Html + Sized {
         self
     }
 
+    /// Add a block of code, as `<pre><code class="language-...">`, to this container
+    ///
+    /// The language class follows the convention used by syntax highlighters such as highlight.js
+    /// and Prism. Unlike most other text methods, the code is escaped, since source code frequently
+    /// contains characters like `<` and `&`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_code_block("if a < b && b > c {}", "rust");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     concat!(
+    ///         r#"<div><pre><code class="language-rust">"#,
+    ///         "if a &lt; b &amp;&amp; b &gt; c {}</code></pre></div>"
+    ///     )
+    /// );
+    /// ```
+    fn add_code_block(&mut self, code: impl ToString, language: impl ToString) {
+        let code = HtmlElement::new_with_text_escaped(HtmlTag::CodeText, code)
+            .with_attribute("class", format!("language-{}", language.to_string()));
+        self.add_element(HtmlElement::new(HtmlTag::PreformattedText).with_child(code.into()));
+    }
+
+    /// Add a block of code, as `<pre><code class="language-...">`, to this container
+    ///
+    /// The language class follows the convention used by syntax highlighters such as highlight.js
+    /// and Prism. Unlike most other text methods, the code is escaped, since source code frequently
+    /// contains characters like `<` and `&`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_code_block("<br/>", "html")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     r#"<div><pre><code class="language-html">&lt;br/&gt;</code></pre></div>"#
+    /// );
+    /// ```
+    fn with_code_block(mut self, code: impl ToString, language: impl ToString) -> Self {
+        self.add_code_block(code, language);
+        self
+    }
+
     /// Add raw content to the container. This content is pasted directly into the HTML
     ///
     /// This is intended to be used as an escape hatch for one-off insertions. If you want a more
@@ -697,4 +2027,58 @@ pub trait HtmlContainer: Html + Sized {
     fn with_raw(self, content: impl ToString) -> Self {
         self.with_html(content.to_string())
     }
+
+    /// Escape the specified content with [`escape_html`], then add it to this container
+    ///
+    /// This is a shorthand for `add_raw(escape_html(...))`, and is appropriate for inserting text
+    /// from untrusted sources.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut content = HtmlElement::new(HtmlTag::Div);
+    /// content.add_raw_escaped("<script>alert('hi')</script>");
+    /// assert_eq!(
+    ///     content.to_html_string(),
+    ///     "<div>&lt;script&gt;alert(&#39;hi&#39;)&lt;/script&gt;</div>"
+    /// );
+    /// ```
+    fn add_raw_escaped(&mut self, content: impl ToString) {
+        self.add_html(escape_html(&content.to_string()));
+    }
+
+    /// Escape the specified content with [`escape_html`], then add it to this container
+    ///
+    /// This is a shorthand for `with_raw(escape_html(...))`, and is appropriate for inserting text
+    /// from untrusted sources.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let content = HtmlElement::new(HtmlTag::Div)
+    ///     .with_raw_escaped("Fish & Chips")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(content, "<div>Fish &amp; Chips</div>");
+    /// ```
+    fn with_raw_escaped(mut self, content: impl ToString) -> Self {
+        self.add_raw_escaped(content);
+        self
+    }
+}
+
+/// Get the tag for a heading of the given level
+///
+/// # Panics
+/// Panics if `level` is not between 1 and 6
+fn heading_tag(level: u8) -> HtmlTag {
+    match level {
+        1 => HtmlTag::Heading1,
+        2 => HtmlTag::Heading2,
+        3 => HtmlTag::Heading3,
+        4 => HtmlTag::Heading4,
+        5 => HtmlTag::Heading5,
+        6 => HtmlTag::Heading6,
+        _ => panic!("'{}' is not a valid html heading level", level),
+    }
 }
@@ -4,7 +4,47 @@
 //! can be made to this file in a patch-level release.
 
 use crate::attributes::Attributes;
-use crate::Html;
+use crate::{Comment, Escaped, Html};
+
+/// A single element in the head of an HTML page
+#[derive(Debug, Clone)]
+pub enum HeadContent {
+    Comment(Comment),
+    Link(Link),
+    Meta(Meta),
+    ScriptLink(ScriptLink),
+    ScriptLiteral(ScriptLiteral),
+    Style(Style),
+    Title(Title),
+}
+
+impl Html for HeadContent {
+    fn to_html_string(&self) -> String {
+        match self {
+            Self::Comment(x) => x.to_html_string(),
+            Self::Link(x) => x.to_html_string(),
+            Self::Meta(x) => x.to_html_string(),
+            Self::ScriptLink(x) => x.to_html_string(),
+            Self::ScriptLiteral(x) => x.to_html_string(),
+            Self::Style(x) => x.to_html_string(),
+            Self::Title(x) => x.to_html_string(),
+        }
+    }
+}
+
+macro_rules! impl_from_for_head_content {
+    ($($variant:ident),*) => {
+        $(
+            impl From<$variant> for HeadContent {
+                fn from(value: $variant) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_head_content!(Comment, Link, Meta, ScriptLink, ScriptLiteral, Style, Title);
 
 /// An HTML link element
 #[derive(Debug, Clone)]
@@ -18,7 +58,9 @@ impl Html for Link {
     fn to_html_string(&self) -> String {
         format!(
             r#"<link href="{}" rel="{}"{}>"#,
-            self.href, self.rel, self.attr
+            Escaped(&self.href),
+            Escaped(&self.rel),
+            self.attr
         )
     }
 }
@@ -42,30 +84,58 @@ pub struct ScriptLink {
 
 impl Html for ScriptLink {
     fn to_html_string(&self) -> String {
-        format!(r#"<script src="{}"{}></script>"#, self.src, self.attr)
+        format!(
+            r#"<script src="{}"{}></script>"#,
+            Escaped(&self.src),
+            self.attr
+        )
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct ScriptLiteral {
     pub code: String,
+    /// Whether the code should be wrapped in a `CDATA` section, as required by XHTML
+    pub cdata: bool,
 }
 
 impl Html for ScriptLiteral {
     fn to_html_string(&self) -> String {
-        format!("<script>{}</script>", self.code)
+        if self.cdata {
+            format!(
+                "<script>//<![CDATA[\n{}\n//]]></script>",
+                split_cdata_end(&self.code)
+            )
+        } else {
+            format!("<script>{}</script>", self.code)
+        }
     }
 }
 
+/// Split each `]]>` in `text` across two `CDATA` sections, so that it cannot end the section early
+fn split_cdata_end(text: &str) -> String {
+    text.replace("]]>", "]]]]><![CDATA[>")
+}
+
 #[derive(Debug, Clone)]
 pub struct Style {
     pub css: String,
     pub attr: Attributes,
+    /// Whether the css should be wrapped in a `CDATA` section, as required by XHTML
+    pub cdata: bool,
 }
 
 impl Html for Style {
     fn to_html_string(&self) -> String {
-        format!("<style{}>{}</style>", self.attr, self.css)
+        if self.cdata {
+            format!(
+                "<style{}>/*<![CDATA[*/\n{}\n/*]]>*/</style>",
+                self.attr,
+                split_cdata_end(&self.css)
+            )
+        } else {
+            format!("<style{}>{}</style>", self.attr, self.css)
+        }
     }
 }
 
@@ -79,3 +149,36 @@ impl Html for Title {
         format!("<title>{}</title>", self.content)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cdata_end_is_split() {
+        // Arrange
+        let script = ScriptLiteral {
+            code: "if (a[b[0]]>1) {}".to_string(),
+            cdata: true,
+        };
+        let style = Style {
+            css: "/* ]]> */".to_string(),
+            attr: Attributes::default(),
+            cdata: true,
+        };
+
+        // Act
+        let script = script.to_html_string();
+        let style = style.to_html_string();
+
+        // Assert
+        assert_eq!(
+            script,
+            "<script>//<![CDATA[\nif (a[b[0]]]]><![CDATA[>1) {}\n//]]></script>"
+        );
+        assert_eq!(
+            style,
+            "<style>/*<![CDATA[*/\n/* ]]]]><![CDATA[> */\n/*]]>*/</style>"
+        );
+    }
+}
@@ -2,7 +2,12 @@
 
 use crate::attributes::Attributes;
 use crate::html_container::HtmlContainer;
-use crate::Html;
+use crate::{Comment, Container, Html, HtmlElement, HtmlTag};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::iter::empty;
+use std::path::Path;
 
 mod header_content;
 mod version;
@@ -27,29 +32,143 @@ pub use version::HtmlVersion;
 ///     "<body><h1>Header Text</h1></body></html>"
 /// ));
 /// ```
-#[derive(Debug, Default)]
+///
+/// Use [`to_html_string_pretty`](Html::to_html_string_pretty) for indented output, such as
+/// generated files that are checked into version control.
+///
+/// ```
+/// # use build_html::*;
+/// let page = HtmlPage::new()
+///     .with_title("My Page")
+///     .with_header(1, "Header Text")
+///     .with_paragraph("Text")
+///     .to_html_string_pretty(2);
+///
+/// assert_eq!(page, concat!(
+///     "<!DOCTYPE html>\n",
+///     "<html>\n",
+///     "  <head>\n",
+///     "    <title>My Page</title>\n",
+///     "  </head>\n",
+///     "  <body>\n",
+///     "    <h1>Header Text</h1>\n",
+///     "    <p>Text</p>\n",
+///     "  </body>\n",
+///     "</html>"
+/// ));
+/// assert!(HtmlPage::new().to_html_string_pretty(2).contains("  <body></body>\n"));
+/// ```
+///
+/// # Templates
+/// Pages can be cloned, so a common base can be built once and then customized for each variation.
+///
+/// ```
+/// # use build_html::*;
+/// let base = HtmlPage::new()
+///     .with_stylesheet("style.css")
+///     .with_header(1, "My Site");
+///
+/// let about = base.clone().with_title("About").with_paragraph("About us");
+/// let contact = base.clone().with_title("Contact");
+///
+/// assert_eq!(about.body_len(), 2);
+/// assert_eq!(contact.head_len(), 2);
+/// assert_eq!(base.body_len(), 1);
+/// ```
+#[derive(Debug, Clone)]
 pub struct HtmlPage {
     version: version::HtmlVersion,
-    head: String,
-    body: String,
+    head: Vec<header_content::HeadContent>,
+    body: HtmlElement,
+}
+
+impl Default for HtmlPage {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Html for HtmlPage {
     fn to_html_string(&self) -> String {
-        format!(
-            "{}<html{}><head>{}</head><body>{}</body></html>",
+        let mut html = String::new();
+        self.render_into(&mut html);
+        html
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        // Writing to a `String` never fails
+        let _ = write!(
+            buf,
+            "{}<html{}><head>",
             self.version.doctype(),
-            self.version.html_attrs(),
-            self.head,
-            self.body,
-        )
+            self.version.html_attrs()
+        );
+        for content in self.head.iter() {
+            content.render_into(buf);
+        }
+        buf.push_str("</head>");
+        if self.body.children.is_empty() {
+            // An empty element would render self-closed, which isn't valid for `<body>`
+            let _ = write!(buf, "{}</body>", self.body.open_tag());
+        } else {
+            self.body.render_into(buf);
+        }
+        buf.push_str("</html>");
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        let pad = " ".repeat(indent);
+        let mut html = String::new();
+        // Writing to a `String` never fails
+        let _ = writeln!(html, "{}", self.version.doctype());
+        let _ = writeln!(html, "<html{}>", self.version.html_attrs());
+        if self.head.is_empty() {
+            let _ = writeln!(html, "{}<head></head>", pad);
+        } else {
+            let _ = writeln!(html, "{}<head>", pad);
+            for content in self.head.iter() {
+                let _ = writeln!(html, "{}{}", pad.repeat(2), content.to_html_string());
+            }
+            let _ = writeln!(html, "{}</head>", pad);
+        }
+        if self.body.children.is_empty() {
+            let _ = writeln!(html, "{}{}</body>", pad, self.body.open_tag());
+        } else {
+            self.body.write_pretty(&mut html, indent, 1);
+        }
+        html.push_str("</html>");
+        html
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        write!(
+            w,
+            "{}<html{}><head>",
+            self.version.doctype(),
+            self.version.html_attrs()
+        )?;
+        for content in self.head.iter() {
+            content.write_html(w)?;
+        }
+        w.write_all(b"</head>")?;
+        if self.body.children.is_empty() {
+            write!(w, "{}</body>", self.body.open_tag())?;
+        } else {
+            self.body.write_html(w)?;
+        }
+        w.write_all(b"</html>")
     }
 }
 
 impl HtmlContainer for HtmlPage {
     #[inline]
     fn add_html<H: Html>(&mut self, html: H) {
-        self.body.push_str(html.to_html_string().as_str());
+        self.body.add_html(html);
+    }
+
+    #[inline]
+    fn add_element(&mut self, element: HtmlElement) {
+        self.body.add_element(element);
     }
 }
 
@@ -76,21 +195,296 @@ impl HtmlPage {
     pub fn with_version(version: HtmlVersion) -> Self {
         HtmlPage {
             version,
-            head: String::new(),
-            body: String::new(),
+            head: Vec::new(),
+            body: HtmlElement::new(HtmlTag::Body),
+        }
+    }
+
+    /// Create a new HTML5 page with room for the specified number of elements in its head and body
+    ///
+    /// This behaves like [`Vec::with_capacity`]: the page is still empty, but at least `head` head
+    /// elements and `body` body elements can be added without reallocating.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::with_capacity(1, 1000);
+    /// for i in 0..1000 {
+    ///     page.add_paragraph(i);
+    /// }
+    /// assert_eq!(page.body_len(), 1000);
+    /// ```
+    pub fn with_capacity(head: usize, body: usize) -> Self {
+        HtmlPage {
+            version: HtmlVersion::HTML5,
+            head: Vec::with_capacity(head),
+            body: HtmlElement {
+                tag: HtmlTag::Body,
+                attributes: Vec::new(),
+                children: Vec::with_capacity(body),
+            },
+        }
+    }
+
+    /// Get the number of elements in the head of this page
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_title("My Page")
+    ///     .with_stylesheet("style.css");
+    /// assert_eq!(page.head_len(), 2);
+    /// ```
+    pub fn head_len(&self) -> usize {
+        self.head.len()
+    }
+
+    /// Get the number of elements in the body of this page
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_title("My Page")
+    ///     .with_header(1, "Header")
+    ///     .with_paragraph("Text")
+    ///     .with_paragraph("More text");
+    /// assert_eq!(page.body_len(), 3);
+    /// ```
+    pub fn body_len(&self) -> usize {
+        self.body.children.len()
+    }
+
+    /// Get the `<body>` element of this page
+    ///
+    /// The body is stored as a structured [`HtmlElement`], so the page can be inspected with the
+    /// same tools as any other element. Elements built by the [`HtmlContainer`] helper methods,
+    /// such as [`add_paragraph`](HtmlContainer::add_paragraph), or added with
+    /// [`add_element`](HtmlContainer::add_element), are kept as
+    /// [`HtmlChild::Element`](crate::HtmlChild::Element). Other content, such as a
+    /// [`Table`](crate::Table) or anything added with [`add_html`](HtmlContainer::add_html) or
+    /// [`add_raw`](HtmlContainer::add_raw), is rendered when it is added and stored as
+    /// [`HtmlChild::Raw`](crate::HtmlChild::Raw).
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new()
+    ///     .with_paragraph("Intro")
+    ///     .with_div([("id", "main")], |div| div.with_paragraph_attr("Text", [("id", "text")]));
+    ///
+    /// let find = |id| page.body().path_to_first(|e| e.matches(None, Some(("id", id))));
+    /// assert_eq!(find("main"), Some(vec![1]));
+    /// assert_eq!(find("text"), Some(vec![1, 0]));
+    ///
+    /// page.add_raw("<p>Raw</p>");
+    /// assert_eq!(page.body().children[2].as_raw(), Some("<p>Raw</p>"));
+    /// ```
+    pub fn body(&self) -> &HtmlElement {
+        &self.body
+    }
+
+    /// Get a mutable reference to the `<body>` element of this page
+    ///
+    /// This allows structured children and attributes to be added to, or edited within, the body.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new().with_paragraph("Text");
+    /// page.body_mut().add_attribute("class", "dark");
+    ///
+    /// assert_eq!(
+    ///     page.to_html_string(),
+    ///     r#"<!DOCTYPE html><html><head></head><body class="dark"><p>Text</p></body></html>"#
+    /// );
+    /// ```
+    pub fn body_mut(&mut self) -> &mut HtmlElement {
+        &mut self.body
+    }
+
+    /// Render only the content of the body of this page
+    ///
+    /// The doctype and the `<html>`, `<head>`, and `<body>` tags are all omitted. This is useful
+    /// when inserting the generated content into an existing layout, such as a server-side
+    /// template.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_title("Ignored")
+    ///     .with_header(1, "Report")
+    ///     .with_paragraph("All systems normal");
+    ///
+    /// assert_eq!(page.body_to_html_string(), "<h1>Report</h1><p>All systems normal</p>");
+    /// ```
+    pub fn body_to_html_string(&self) -> String {
+        let mut html = String::new();
+        for child in self.body.children.iter() {
+            child.render_into(&mut html);
         }
+        html
+    }
+
+    /// Render this page and write it to the file at the specified path
+    ///
+    /// The file will be created if it does not exist, and its contents will be replaced if it
+    /// does.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let path = std::env::temp_dir().join("build_html_write_to_file.html");
+    /// let page = HtmlPage::new().with_title("My Page");
+    /// page.write_to_file(&path).unwrap();
+    ///
+    /// assert_eq!(std::fs::read_to_string(&path).unwrap(), page.to_html_string());
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+        self.write_html(&mut file)?;
+        io::Write::flush(&mut file)
+    }
+
+    /// Replace the body of this page with the contents of the specified `Container`
+    ///
+    /// The container itself is not rendered; only its children are placed inside the `<body>`.
+    /// This allows the body to be composed separately with the full [`HtmlContainer`] API and
+    /// attached afterward.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new().with_paragraph("Replaced");
+    /// page.set_body(Container::default().with_header(1, "Header").with_paragraph("Text"));
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head></head>",
+    ///     "<body><h1>Header</h1><p>Text</p></body></html>"
+    /// ));
+    /// ```
+    pub fn set_body(&mut self, body: Container) {
+        self.body.children = body.0.children;
+    }
+
+    /// Replace the body of this page with the contents of the specified `Container`
+    ///
+    /// The container itself is not rendered; only its children are placed inside the `<body>`.
+    /// This allows the body to be composed separately with the full [`HtmlContainer`] API and
+    /// attached afterward.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let body = Container::default()
+    ///     .with_header(1, "Header")
+    ///     .with_paragraph("Text");
+    ///
+    /// let page = HtmlPage::new()
+    ///     .with_title("My Page")
+    ///     .with_body(body)
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head><title>My Page</title></head>",
+    ///     "<body><h1>Header</h1><p>Text</p></body></html>"
+    /// ));
+    /// ```
+    pub fn with_body(mut self, body: Container) -> Self {
+        self.set_body(body);
+        self
     }
 
     /// Helper function similar to [`HtmlContainer::add_html`]
     #[inline]
-    fn add_html_head<H: Html>(&mut self, html: H) {
-        self.head.push_str(html.to_html_string().as_str());
+    fn add_html_head(&mut self, content: impl Into<header_content::HeadContent>) {
+        self.head.push(content.into());
     }
 
     /// Helper function similar to [`HtmlContainer::with_html`]
     #[inline]
-    fn with_html_head<H: Html>(mut self, html: H) -> Self {
-        self.add_html_head(html);
+    fn with_html_head(mut self, content: impl Into<header_content::HeadContent>) -> Self {
+        self.add_html_head(content);
+        self
+    }
+
+    /// Adds a comment to the HTML head.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_head_comment(" build: abc123 ");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     "<!-- build: abc123 -->",
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_head_comment(&mut self, text: impl Into<String>) {
+        self.add_html_head(Comment::from(text))
+    }
+
+    /// Adds a comment to the HTML head.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_head_comment(" build: abc123 ")
+    ///     .with_title("My Page")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     "<!-- build: abc123 --><title>My Page</title>",
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_head_comment(self, text: impl Into<String>) -> Self {
+        self.with_html_head(Comment::from(text))
+    }
+
+    /// Adds a comment to the HTML body.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_comment(" generated ");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head></head><body>",
+    ///     "<!-- generated -->",
+    ///     "</body></html>"
+    /// ));
+    /// ```
+    pub fn add_comment(&mut self, text: impl Into<String>) {
+        self.body.add_child(Comment::from(text).into())
+    }
+
+    /// Adds a comment to the HTML body.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_comment(" generated ")
+    ///     .with_paragraph("Text")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head></head><body>",
+    ///     "<!-- generated --><p>Text</p>",
+    ///     "</body></html>"
+    /// ));
+    /// ```
+    pub fn with_comment(mut self, text: impl Into<String>) -> Self {
+        self.add_comment(text);
         self
     }
 
@@ -139,6 +533,61 @@ impl HtmlPage {
         })
     }
 
+    /// Adds a new link to the HTML head whose `rel` attribute contains several tokens
+    ///
+    /// The tokens are joined with single spaces.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_head_link_rels("favicon.ico", ["shortcut", "icon"]);
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="favicon.ico" rel="shortcut icon">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_head_link_rels<I>(&mut self, href: impl ToString, rels: I)
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        let rel = rels
+            .into_iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.add_head_link(href, rel)
+    }
+
+    /// Adds a new link to the HTML head whose `rel` attribute contains several tokens
+    ///
+    /// The tokens are joined with single spaces.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_head_link_rels("alt.css", ["alternate", "stylesheet"])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="alt.css" rel="alternate stylesheet">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_head_link_rels<I>(mut self, href: impl ToString, rels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.add_head_link_rels(href, rels);
+        self
+    }
+
     /// Adds a new link to the HTML head with the specified additional attributes
     ///
     /// # Example
@@ -194,7 +643,8 @@ impl HtmlPage {
 
     /// Adds the specified metadata elements to this `HtmlPage`
     ///
-    /// Attributes are specified in a `HashMap`
+    /// Attributes can be specified using any type that implements [`IntoIterator`] for 2-tuples,
+    /// such as an array or a `HashMap`
     ///
     /// # Example
     /// ```
@@ -220,7 +670,8 @@ impl HtmlPage {
 
     /// Adds the specified metadata elements to this `HtmlPage`
     ///
-    /// Attributes are specified in a `HashMap`
+    /// Attributes can be specified using any type that implements [`IntoIterator`] for 2-tuples,
+    /// such as an array or a `HashMap`
     ///
     /// # Example
     /// ```
@@ -336,9 +787,23 @@ impl HtmlPage {
     /// let mut page = HtmlPage::new();
     /// page.add_script_literal(include_str!("myScript.js"));
     /// ```
+    ///
+    /// If this page uses an XHTML version, the script will be wrapped in a `CDATA` section so that
+    /// it remains valid XML:
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::with_version(HtmlVersion::XHTML1_0);
+    /// page.add_script_literal("if (a < b) { go(); }");
+    ///
+    /// assert!(page.to_html_string().contains(
+    ///     "<script>//<![CDATA[\nif (a < b) { go(); }\n//]]></script>"
+    /// ));
+    /// ```
     pub fn add_script_literal(&mut self, code: impl ToString) {
         self.add_html_head(header_content::ScriptLiteral {
             code: code.to_string(),
+            cdata: self.version.is_xhtml(),
         })
     }
 
@@ -367,10 +832,9 @@ impl HtmlPage {
     ///     .with_script_literal(include_str!("myScript.js"))
     ///     .to_html_string();
     /// ```
-    pub fn with_script_literal(self, code: impl ToString) -> Self {
-        self.with_html_head(header_content::ScriptLiteral {
-            code: code.to_string(),
-        })
+    pub fn with_script_literal(mut self, code: impl ToString) -> Self {
+        self.add_script_literal(code);
+        self
     }
 
     /// Adds raw style data to this `HtmlPage`
@@ -395,11 +859,21 @@ impl HtmlPage {
     /// let mut page = HtmlPage::new();
     /// page.add_style(include_str!("styles.css"));
     /// ```
+    ///
+    /// If this page uses an XHTML version, the style will be wrapped in a `CDATA` section so that
+    /// it remains valid XML:
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::with_version(HtmlVersion::XHTML1_1);
+    /// page.add_style("p > a{color:red;}");
+    ///
+    /// assert!(page.to_html_string().contains(
+    ///     "<style>/*<![CDATA[*/\np > a{color:red;}\n/*]]>*/</style>"
+    /// ));
+    /// ```
     pub fn add_style(&mut self, css: impl ToString) {
-        self.add_html_head(header_content::Style {
-            css: css.to_string(),
-            attr: Attributes::default(),
-        })
+        self.add_style_attr(css, empty::<(&str, &str)>())
     }
 
     /// Adds raw style data to this `HtmlPage`
@@ -426,14 +900,28 @@ impl HtmlPage {
     ///     .with_style(include_str!("styles.css"))
     ///     .to_html_string();
     /// ```
-    pub fn with_style(self, css: impl ToString) -> Self {
-        self.with_html_head(header_content::Style {
-            css: css.to_string(),
-            attr: Attributes::default(),
-        })
+    pub fn with_style(mut self, css: impl ToString) -> Self {
+        self.add_style(css);
+        self
     }
 
     /// Adds the specified style data with the specified attributes
+    ///
+    /// Attributes can be specified using any type that implements [`IntoIterator`] for 2-tuples,
+    /// such as an array or a `HashMap`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_style_attr("p{color:black;}", [("media", "print")]);
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<style media="print">p{color:black;}</style>"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
     pub fn add_style_attr<A, S>(&mut self, css: impl ToString, attributes: A)
     where
         A: IntoIterator<Item = (S, S)>,
@@ -442,19 +930,81 @@ impl HtmlPage {
         self.add_html_head(header_content::Style {
             css: css.to_string(),
             attr: attributes.into(),
+            cdata: self.version.is_xhtml(),
         })
     }
 
     /// Adds the specified style data with the specified attributes
-    pub fn with_style_attr<A, S>(self, css: impl ToString, attributes: A) -> Self
-    where
+    ///
+    /// Attributes can be specified using any type that implements [`IntoIterator`] for 2-tuples,
+    /// such as an array or a `HashMap`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_style_attr("p{color:black;}", [("nonce", "r4nd0m")])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<style nonce="r4nd0m">p{color:black;}</style>"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_style_attr<A, S>(mut self, css: impl ToString, attributes: A) -> Self
+    where
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        self.with_html_head(header_content::Style {
-            css: css.to_string(),
-            attr: attributes.into(),
-        })
+        self.add_style_attr(css, attributes);
+        self
+    }
+
+    /// Adds raw style data with the specified `id` to this `HtmlPage`
+    ///
+    /// Naming style blocks allows the styles contributed by different components of a page to be
+    /// distinguished from each other.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_named_style("base", "p{margin:0;}");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<style id="base">p{margin:0;}</style>"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_named_style(&mut self, id: impl ToString, css: impl ToString) {
+        self.add_style_attr(css, [("id", id.to_string().as_str())]);
+    }
+
+    /// Adds raw style data with the specified `id` to this `HtmlPage`
+    ///
+    /// Naming style blocks allows the styles contributed by different components of a page to be
+    /// distinguished from each other.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_named_style("base", "p{margin:0;}")
+    ///     .with_named_style("cards", ".card{padding:1em;}")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<style id="base">p{margin:0;}</style>"#,
+    ///     r#"<style id="cards">.card{padding:1em;}</style>"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_named_style(mut self, id: impl ToString, css: impl ToString) -> Self {
+        self.add_named_style(id, css);
+        self
     }
 
     /// Adds the specified stylesheet to the HTML head.
@@ -500,32 +1050,287 @@ impl HtmlPage {
         self.with_head_link(source, "stylesheet")
     }
 
-    /// Adds a title to this HTML page
+    /// Adds a link to the specified web app manifest to the HTML head.
+    ///
+    /// This method uses [`add_head_link`](HtmlPage::add_head_link) internally
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
     /// let mut page = HtmlPage::new();
+    /// page.add_manifest("manifest.json");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="manifest.json" rel="manifest">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    #[inline]
+    pub fn add_manifest(&mut self, href: impl ToString) {
+        self.add_head_link(href, "manifest")
+    }
+
+    /// Adds a link to the specified web app manifest to the HTML head.
+    ///
+    /// This method uses [`add_head_link`](HtmlPage::add_head_link) internally
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_manifest("manifest.json")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="manifest.json" rel="manifest">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    #[inline]
+    pub fn with_manifest(self, href: impl ToString) -> Self {
+        self.with_head_link(href, "manifest")
+    }
+
+    /// Adds a resource hint, such as `preload` or `dns-prefetch`, to the HTML head.
+    ///
+    /// This method uses [`add_head_link_attr`](HtmlPage::add_head_link_attr) internally
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_resource_hint("preload", "font.woff2", [("as", "font"), ("crossorigin", "")]);
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="font.woff2" rel="preload" as="font" crossorigin="">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_resource_hint<A, S>(&mut self, rel: impl ToString, href: impl ToString, attr: A)
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_head_link_attr(href, rel, attr)
+    }
+
+    /// Adds a resource hint, such as `preload` or `dns-prefetch`, to the HTML head.
+    ///
+    /// This method uses [`add_head_link_attr`](HtmlPage::add_head_link_attr) internally
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_resource_hint("dns-prefetch", "https://cdn.example.com", attrs! {})
+    ///     .with_resource_hint("preload", "app.js", [("as", "script")])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="https://cdn.example.com" rel="dns-prefetch">"#,
+    ///     r#"<link href="app.js" rel="preload" as="script">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_resource_hint<A, S>(
+        mut self,
+        rel: impl ToString,
+        href: impl ToString,
+        attr: A,
+    ) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_resource_hint(rel, href, attr);
+        self
+    }
+
+    /// Adds a `preconnect` resource hint to the HTML head for each of the specified origins.
+    ///
+    /// Resources fetched in CORS mode, such as web fonts, need the `crossorigin` attribute on
+    /// their hint; use [`add_resource_hint`](HtmlPage::add_resource_hint) for those origins.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_preconnect(["https://cdn.example.com", "https://api.example.com"]);
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="https://cdn.example.com" rel="preconnect">"#,
+    ///     r#"<link href="https://api.example.com" rel="preconnect">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_preconnect<I>(&mut self, origins: I)
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        for origin in origins {
+            self.add_head_link(origin, "preconnect");
+        }
+    }
+
+    /// Adds a `preconnect` resource hint to the HTML head for each of the specified origins.
+    ///
+    /// Resources fetched in CORS mode, such as web fonts, need the `crossorigin` attribute on
+    /// their hint; use [`with_resource_hint`](HtmlPage::with_resource_hint) for those origins.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_preconnect(["https://cdn.example.com"])
+    ///     .with_resource_hint("preconnect", "https://fonts.example.com", [("crossorigin", "")])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="https://cdn.example.com" rel="preconnect">"#,
+    ///     r#"<link href="https://fonts.example.com" rel="preconnect" crossorigin="">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_preconnect<I>(mut self, origins: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        self.add_preconnect(origins);
+        self
+    }
+
+    /// Adds a `theme-color` metadata element with the specified color to the HTML head.
+    ///
+    /// This method uses [`add_meta`](HtmlPage::add_meta) internally
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_theme_color("#336699");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r##"<meta name="theme-color" content="#336699">"##,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_theme_color(&mut self, color: impl ToString) {
+        let color = color.to_string();
+        self.add_meta([("name", "theme-color"), ("content", color.as_str())])
+    }
+
+    /// Adds a `theme-color` metadata element with the specified color to the HTML head.
+    ///
+    /// This method uses [`add_meta`](HtmlPage::add_meta) internally
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_manifest("manifest.json")
+    ///     .with_theme_color("#336699")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<link href="manifest.json" rel="manifest">"#,
+    ///     r##"<meta name="theme-color" content="#336699">"##,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_theme_color(mut self, color: impl ToString) -> Self {
+        self.add_theme_color(color);
+        self
+    }
+
+    /// Adds a `refresh` metadata element to the HTML head, which redirects to the specified URL
+    /// after the given number of seconds.
+    ///
+    /// This method uses [`add_meta`](HtmlPage::add_meta) internally
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new();
+    /// page.add_meta_refresh(5, "/next");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<meta http-equiv="refresh" content="5;url=/next">"#,
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_meta_refresh(&mut self, seconds: u32, url: impl ToString) {
+        let content = format!("{};url={}", seconds, url.to_string());
+        self.add_meta([("http-equiv", "refresh"), ("content", content.as_str())])
+    }
+
+    /// Adds a `refresh` metadata element to the HTML head, which redirects to the specified URL
+    /// after the given number of seconds.
+    ///
+    /// This method uses [`add_meta`](HtmlPage::add_meta) internally
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_meta_refresh(0, "https://example.com/new-home")
+    ///     .with_paragraph("This page has moved")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     r#"<meta http-equiv="refresh" content="0;url=https://example.com/new-home">"#,
+    ///     "</head><body><p>This page has moved</p></body></html>"
+    /// ));
+    /// ```
+    pub fn with_meta_refresh(mut self, seconds: u32, url: impl ToString) -> Self {
+        self.add_meta_refresh(seconds, url);
+        self
+    }
+
+    /// Set the title of this HTML page, replacing any existing title
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new()
+    ///     .with_title("Draft")
+    ///     .with_stylesheet("style.css");
     /// page.add_title("My Page");
     ///
     /// assert_eq!(page.to_html_string(), concat!(
     ///     "<!DOCTYPE html><html><head>",
-    ///     "<title>My Page</title>",
+    ///     r#"<title>My Page</title><link href="style.css" rel="stylesheet">"#,
     ///     "</head><body></body></html>"
     /// ));
     /// ```
     pub fn add_title(&mut self, title_text: impl ToString) {
-        self.add_html_head(header_content::Title {
-            content: title_text.to_string(),
-        })
+        // A document may only have one title, so an existing title is replaced in place
+        match self.title_mut() {
+            Some(title) => title.content = title_text.to_string(),
+            None => self.add_html_head(header_content::Title {
+                content: title_text.to_string(),
+            }),
+        }
     }
 
-    /// Adds a title to this HTML page
+    /// Set the title of this HTML page, replacing any existing title
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
     /// let page = HtmlPage::new()
+    ///     .with_title("Draft")
     ///     .with_title("My Page")
     ///     .to_html_string();
     ///
@@ -535,9 +1340,125 @@ impl HtmlPage {
     ///     "</head><body></body></html>"
     /// ));
     /// ```
-    pub fn with_title(self, title_text: impl ToString) -> Self {
-        self.with_html_head(header_content::Title {
-            content: title_text.to_string(),
+    pub fn with_title(mut self, title_text: impl ToString) -> Self {
+        self.add_title(title_text);
+        self
+    }
+
+    /// Append a suffix, such as the name of the site, to the title of this HTML page
+    ///
+    /// The suffix is joined to the existing title with the given separator. If the page does not
+    /// have a title yet, the site name alone becomes the title. Since the suffix is applied to the
+    /// current title, set the title first.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new().with_title("About");
+    /// page.add_title_suffix(" | ", "My Site");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     "<title>About | My Site</title>",
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_title_suffix(&mut self, separator: impl ToString, site_name: impl ToString) {
+        match self.title_mut() {
+            Some(title) => {
+                title.content.push_str(&separator.to_string());
+                title.content.push_str(&site_name.to_string());
+            }
+            None => self.add_title(site_name),
+        }
+    }
+
+    /// Append a suffix, such as the name of the site, to the title of this HTML page
+    ///
+    /// The suffix is joined to the existing title with the given separator. If the page does not
+    /// have a title yet, the site name alone becomes the title. Since the suffix is applied to the
+    /// current title, set the title first.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_title("Contact")
+    ///     .with_title_suffix(" — ", "My Site")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     "<title>Contact — My Site</title>",
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_title_suffix(mut self, separator: impl ToString, site_name: impl ToString) -> Self {
+        self.add_title_suffix(separator, site_name);
+        self
+    }
+
+    /// Prepend a prefix, such as a section or notification count, to the title of this HTML page
+    ///
+    /// The prefix is joined to the existing title with the given separator. If the page does not
+    /// have a title yet, the prefix alone becomes the title. Since the prefix is applied to the
+    /// current title, set the title first.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut page = HtmlPage::new().with_title("My Site");
+    /// page.add_title_prefix(" | ", "About");
+    ///
+    /// assert_eq!(page.to_html_string(), concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     "<title>About | My Site</title>",
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn add_title_prefix(&mut self, separator: impl ToString, prefix: impl ToString) {
+        match self.title_mut() {
+            Some(title) => {
+                let mut content = prefix.to_string();
+                content.push_str(&separator.to_string());
+                content.push_str(&title.content);
+                title.content = content;
+            }
+            None => self.add_title(prefix),
+        }
+    }
+
+    /// Prepend a prefix, such as a section or notification count, to the title of this HTML page
+    ///
+    /// The prefix is joined to the existing title with the given separator. If the page does not
+    /// have a title yet, the prefix alone becomes the title. Since the prefix is applied to the
+    /// current title, set the title first. Prefixes and suffixes can be combined.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let page = HtmlPage::new()
+    ///     .with_title("Inbox")
+    ///     .with_title_suffix(" — ", "My Site")
+    ///     .with_title_prefix(" ", "(3)")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(page, concat!(
+    ///     "<!DOCTYPE html><html><head>",
+    ///     "<title>(3) Inbox — My Site</title>",
+    ///     "</head><body></body></html>"
+    /// ));
+    /// ```
+    pub fn with_title_prefix(mut self, separator: impl ToString, prefix: impl ToString) -> Self {
+        self.add_title_prefix(separator, prefix);
+        self
+    }
+
+    /// Find the first title in the head of this page
+    fn title_mut(&mut self) -> Option<&mut header_content::Title> {
+        self.head.iter_mut().find_map(|content| match content {
+            header_content::HeadContent::Title(title) => Some(title),
+            _ => None,
         })
     }
 }
@@ -545,6 +1466,7 @@ impl HtmlPage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Article;
 
     #[test]
     fn default() {
@@ -560,4 +1482,81 @@ mod tests {
             "<!DOCTYPE html><html><head></head><body></body></html>"
         )
     }
+
+    #[test]
+    fn test_empty_body_with_attributes() {
+        // Arrange
+        let mut sut = HtmlPage::new();
+        sut.body_mut().add_attribute("class", "dark");
+
+        // Act
+        let mut streamed = Vec::new();
+        sut.write_html(&mut streamed).unwrap();
+
+        // Assert
+        let expected = r#"<!DOCTYPE html><html><head></head><body class="dark"></body></html>"#;
+        assert_eq!(sut.to_html_string(), expected);
+        assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+        assert!(sut
+            .to_html_string_pretty(2)
+            .contains("\n  <body class=\"dark\"></body>\n"));
+        assert!(sut.body().children.is_empty());
+    }
+
+    #[test]
+    fn test_helpers_keep_elements_structured() {
+        // Arrange
+        let mut sut = HtmlPage::new();
+
+        // Act
+        sut.add_article(Article::new("Title").with_paragraph("Body"));
+        sut.add_lazy_iframe("map.html", "Map");
+        sut.add_collapsible_section("faq", "FAQ", "Answer");
+
+        // Assert
+        let tags: Vec<_> = sut
+            .body()
+            .children
+            .iter()
+            .filter_map(|child| child.as_element().map(|e| &e.tag))
+            .collect();
+        assert_eq!(
+            tags,
+            [&HtmlTag::Article, &HtmlTag::Iframe, &HtmlTag::Details]
+        );
+        assert_eq!(
+            sut.body().children[0]
+                .as_element()
+                .unwrap()
+                .to_html_string(),
+            "<article><header><h1>Title</h1></header><p>Body</p></article>"
+        );
+    }
+
+    #[test]
+    fn test_comments_are_neutralized() {
+        // Arrange
+        let texts = [">a", "->b", "c <!-- d", "e --> f", "g --!> h", "i <!-"];
+        let expected = concat!(
+            "<!--&gt;a--><!---&gt;b--><!--c &lt;!-- d-->",
+            "<!--e --&gt; f--><!--g --!&gt; h--><!--i &lt;!--->"
+        );
+        let mut sut = HtmlPage::new();
+
+        // Act
+        for text in texts {
+            sut.add_head_comment(text);
+            sut.add_comment(text);
+        }
+        let mut streamed = Vec::new();
+        sut.write_html(&mut streamed).unwrap();
+
+        // Assert
+        let html = format!(
+            "<!DOCTYPE html><html><head>{0}</head><body>{0}</body></html>",
+            expected
+        );
+        assert_eq!(sut.to_html_string(), html);
+        assert_eq!(String::from_utf8(streamed).unwrap(), html);
+    }
 }
@@ -31,7 +31,7 @@ use crate::attributes::Attributes;
 /// some tags or attributes may not be valid in older HTML versions. You are responsible for
 /// knowing which subset of the provided features are valid for your chosen version. Use this
 /// feature at your own risk.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum HtmlVersion {
     /// HTML 5. The current and preferred version of the HTML standard.
@@ -63,6 +63,18 @@ impl HtmlVersion {
         }
     }
 
+    /// Return whether this version is one of the XHTML standards
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::HtmlVersion;
+    /// assert!(HtmlVersion::XHTML1_0.is_xhtml());
+    /// assert!(!HtmlVersion::HTML5.is_xhtml());
+    /// ```
+    pub fn is_xhtml(&self) -> bool {
+        matches!(self, Self::XHTML1_0 | Self::XHTML1_1)
+    }
+
     /// Return the set of attributes that should be applied to the `HtmlPage`'s opening HTML tag
     pub fn html_attrs(&self) -> Attributes {
         match self {
@@ -0,0 +1,205 @@
+//! This module contains the `Iframe` struct, used to embed other pages with `<iframe>` tags
+
+use crate::{Html, HtmlElement, HtmlTag};
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+/// The values of the `referrerpolicy` attribute, which controls what referrer information is sent
+/// when loading an [`Iframe`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReferrerPolicy {
+    /// Never send a `Referer` header
+    NoReferrer,
+    /// Don't send a `Referer` header to less secure origins
+    NoReferrerWhenDowngrade,
+    /// Send only the origin of the embedding page
+    Origin,
+    /// Send the full URL to the same origin, and only the origin to others
+    OriginWhenCrossOrigin,
+    /// Send the full URL to the same origin, and nothing to others
+    SameOrigin,
+    /// Send only the origin, and only to origins that are at least as secure
+    StrictOrigin,
+    /// The browser default: send the full URL to the same origin, and the origin to others
+    StrictOriginWhenCrossOrigin,
+    /// Always send the full URL
+    UnsafeUrl,
+}
+
+impl Display for ReferrerPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NoReferrer => "no-referrer",
+            Self::NoReferrerWhenDowngrade => "no-referrer-when-downgrade",
+            Self::Origin => "origin",
+            Self::OriginWhenCrossOrigin => "origin-when-cross-origin",
+            Self::SameOrigin => "same-origin",
+            Self::StrictOrigin => "strict-origin",
+            Self::StrictOriginWhenCrossOrigin => "strict-origin-when-cross-origin",
+            Self::UnsafeUrl => "unsafe-url",
+        })
+    }
+}
+
+/// An `<iframe>` used to embed third-party content, such as maps or videos
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let iframe = Iframe::new("https://example.com/embed")
+///     .with_title("Example video")
+///     .with_lazy()
+///     .with_allow(["fullscreen", "autoplay"])
+///     .with_referrer_policy(ReferrerPolicy::StrictOrigin)
+///     .to_html_string();
+///
+/// assert_eq!(
+///     iframe,
+///     concat!(
+///         r#"<iframe src="https://example.com/embed" title="Example video" loading="lazy" "#,
+///         r#"allow="fullscreen; autoplay" referrerpolicy="strict-origin"></iframe>"#
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Iframe(HtmlElement);
+
+impl Html for Iframe {
+    fn to_html_string(&self) -> String {
+        self.0.to_html_string()
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        self.0.render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        self.0.to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        self.0.write_html(w)
+    }
+}
+
+impl From<Iframe> for HtmlElement {
+    fn from(iframe: Iframe) -> Self {
+        iframe.0
+    }
+}
+
+impl Iframe {
+    /// Creates a new `Iframe` which embeds the specified URL
+    pub fn new(src: impl ToString) -> Self {
+        // `<iframe>` is not a void element, so the empty child forces a closing tag
+        Self(
+            HtmlElement::new(HtmlTag::Iframe)
+                .with_attribute("src", src)
+                .with_child("".into()),
+        )
+    }
+
+    /// Set the title of this frame, which describes its content to assistive technology
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let iframe = Iframe::new("map.html").with_title("Office location").to_html_string();
+    /// assert_eq!(iframe, r#"<iframe src="map.html" title="Office location"></iframe>"#);
+    /// ```
+    pub fn with_title(mut self, title: impl ToString) -> Self {
+        self.0.add_attribute("title", title);
+        self
+    }
+
+    /// Defer loading this frame until it is near the viewport, by setting `loading="lazy"`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let iframe = Iframe::new("map.html").with_lazy().to_html_string();
+    /// assert_eq!(iframe, r#"<iframe src="map.html" loading="lazy"></iframe>"#);
+    /// ```
+    pub fn with_lazy(mut self) -> Self {
+        self.0.add_attribute("loading", "lazy");
+        self
+    }
+
+    /// Set the permissions policy of this frame, such as `fullscreen` or `autoplay`
+    ///
+    /// The features are joined with `; `, as required by the `allow` attribute.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let iframe = Iframe::new("video.html").with_allow(["fullscreen"]).to_html_string();
+    /// assert_eq!(iframe, r#"<iframe src="video.html" allow="fullscreen"></iframe>"#);
+    /// ```
+    pub fn with_allow<I>(mut self, features: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToString,
+    {
+        let allow = features
+            .into_iter()
+            .map(|f| f.to_string())
+            .collect::<Vec<_>>()
+            .join("; ");
+        self.0.add_attribute("allow", allow);
+        self
+    }
+
+    /// Set the referrer policy used when loading this frame
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let iframe = Iframe::new("map.html")
+    ///     .with_referrer_policy(ReferrerPolicy::NoReferrer)
+    ///     .to_html_string();
+    /// assert_eq!(iframe, r#"<iframe src="map.html" referrerpolicy="no-referrer"></iframe>"#);
+    /// ```
+    pub fn with_referrer_policy(mut self, policy: ReferrerPolicy) -> Self {
+        self.0.add_attribute("referrerpolicy", policy);
+        self
+    }
+
+    /// Associates the specified map of attributes with this `Iframe`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let iframe = Iframe::new("map.html")
+    ///     .with_attributes([("width", "600"), ("height", "450")])
+    ///     .to_html_string();
+    /// assert_eq!(iframe, r#"<iframe src="map.html" width="600" height="450"></iframe>"#);
+    /// ```
+    pub fn with_attributes<A, S>(mut self, attributes: A) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.0.add_attributes(attributes);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_iframe() {
+        // Act
+        let sut = Iframe::new("map.html").with_title("Map");
+
+        // Assert
+        let expected = r#"<iframe src="map.html" title="Map"></iframe>"#;
+        assert_eq!(sut.to_html_string(), expected);
+        assert_eq!(sut.to_html_string_pretty(2), expected);
+
+        let mut streamed = Vec::new();
+        sut.write_html(&mut streamed).unwrap();
+        assert_eq!(String::from_utf8(streamed).unwrap(), expected);
+    }
+}
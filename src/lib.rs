@@ -106,20 +106,71 @@
 //! [`HtmlContainer::add_raw`]. (Note that `HtmlElement` implements `HtmlContainer`, so these
 //! methods will work for that type too.)
 
+mod article;
 mod attributes;
 mod container;
+mod details;
+mod dialog;
 mod elements;
 mod html_container;
 mod html_page;
+mod iframe;
+mod picture;
+mod pretty;
 mod table;
 mod tags;
+mod template;
+mod validation;
 
+pub use self::article::Article;
+pub use self::attributes::{Direction, QuoteStyle};
 pub use self::container::{Container, ContainerType};
+pub use self::details::Details;
+pub use self::dialog::Dialog;
 pub use self::elements::{HtmlChild, HtmlElement};
 pub use self::html_container::HtmlContainer;
 pub use self::html_page::{HtmlPage, HtmlVersion};
-pub use self::table::{Table, TableCell, TableCellType, TableRow};
-pub use self::tags::HtmlTag;
+pub use self::iframe::{Iframe, ReferrerPolicy};
+pub use self::picture::Picture;
+pub use self::table::{Align, Table, TableCell, TableCellType, TableRow, ToTableRow};
+pub use self::tags::{CustomTagName, HtmlTag, ParseHtmlTagError};
+pub use self::template::Template;
+pub use self::validation::ValidationWarning;
+
+use std::borrow::Cow;
+use std::io;
+use std::rc::Rc;
+use std::sync::{Arc, OnceLock};
+
+/// Build a list of attributes that can be passed to any method accepting attributes
+///
+/// Keys can be written either as bare identifiers or as string literals, which is useful for keys
+/// containing dashes. Values can be any expression implementing [`ToString`].
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let content = HtmlElement::new(HtmlTag::Div)
+///     .with_paragraph_attr("text", attrs! { id => "intro", class => "lead", "data-index" => 1 })
+///     .to_html_string();
+///
+/// assert_eq!(content, r#"<div><p id="intro" class="lead" data-index="1">text</p></div>"#);
+/// ```
+#[macro_export]
+macro_rules! attrs {
+    () => {
+        [] as [(::std::string::String, ::std::string::String); 0]
+    };
+    ($($key:tt => $value:expr),+ $(,)?) => {
+        [$(($crate::attrs!(@key $key), ::std::string::ToString::to_string(&$value))),+]
+    };
+    (@key $key:ident) => {
+        ::std::string::String::from(stringify!($key))
+    };
+    (@key $key:literal) => {
+        ::std::string::ToString::to_string(&$key)
+    };
+}
 
 /// An element that can be converted to an HTML string
 ///
@@ -141,6 +192,131 @@ pub trait Html: std::fmt::Debug {
     /// assert_eq!(html, "<div><p>My p element</p></div>");
     /// ```
     fn to_html_string(&self) -> String;
+
+    /// Convert this element into an HTML string, borrowing where possible
+    ///
+    /// For leaf values that already hold their rendered form, such as `String`s, `&str`s, and
+    /// [`HtmlChild::Raw`], this avoids copying the underlying data. All other types fall back to
+    /// an owned string produced by [`to_html_string`](Html::to_html_string).
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// # use std::borrow::Cow;
+    /// let raw = HtmlChild::Raw("<p>text</p>".to_string());
+    /// assert!(matches!(raw.to_html_cow(), Cow::Borrowed("<p>text</p>")));
+    ///
+    /// let element = HtmlElement::new(HtmlTag::Div).with_paragraph("text");
+    /// assert!(matches!(element.to_html_cow(), Cow::Owned(_)));
+    /// assert_eq!(element.to_html_cow(), "<div><p>text</p></div>");
+    /// ```
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_html_string())
+    }
+
+    /// Convert this element into HTML, encoded as UTF-8 bytes
+    ///
+    /// This is convenient for web frameworks whose response bodies are built from bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let html = HtmlElement::new(HtmlTag::Div)
+    ///     .with_paragraph("My p element")
+    ///     .to_html_bytes();
+    ///
+    /// assert_eq!(html, b"<div><p>My p element</p></div>");
+    /// ```
+    fn to_html_bytes(&self) -> Vec<u8> {
+        self.to_html_string().into_bytes()
+    }
+
+    /// Append the HTML for this element to the end of an existing string
+    ///
+    /// This allows a single buffer to be reused across many renders. Structured types such as
+    /// [`HtmlElement`] and [`HtmlPage`] write directly into the buffer rather than allocating a
+    /// fresh string for each node.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut buf = String::new();
+    /// for text in ["One", "Two"] {
+    ///     HtmlElement::new(HtmlTag::ParagraphText)
+    ///         .with_child(text.into())
+    ///         .render_into(&mut buf);
+    /// }
+    ///
+    /// assert_eq!(buf, "<p>One</p><p>Two</p>");
+    /// ```
+    fn render_into(&self, buf: &mut String) {
+        buf.push_str(&self.to_html_cow());
+    }
+
+    /// Convert this element into an indented HTML string, with one element per line
+    ///
+    /// Block elements, such as `<div>`, `<table>`, and `<ul>`, put each of their children on a
+    /// separate line, indented by `indent` spaces per level of nesting. Other elements, such as
+    /// `<p>`, stay on a single line with their content. Raw HTML cannot be parsed, so it is passed
+    /// through verbatim on its own line.
+    ///
+    /// Note that this adds whitespace between the children of block elements, which may be
+    /// visible in some layouts. It is intended for output that is read by people, such as reports
+    /// checked into version control.
+    ///
+    /// The default implementation has no structure to walk, so it simply returns
+    /// [`to_html_string`](Html::to_html_string).
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let html = HtmlElement::new(HtmlTag::Div)
+    ///     .with_child(HtmlElement::new_with_text(HtmlTag::Heading1, "Title").into())
+    ///     .with_child(
+    ///         HtmlElement::new(HtmlTag::UnorderedList)
+    ///             .with_child(HtmlElement::new_with_text(HtmlTag::ListElement, "One").into())
+    ///             .with_child(HtmlElement::new_with_text(HtmlTag::ListElement, "Two").into())
+    ///             .into(),
+    ///     )
+    ///     .to_html_string_pretty(2);
+    ///
+    /// assert_eq!(html, concat!(
+    ///     "<div>\n",
+    ///     "  <h1>Title</h1>\n",
+    ///     "  <ul>\n",
+    ///     "    <li>One</li>\n",
+    ///     "    <li>Two</li>\n",
+    ///     "  </ul>\n",
+    ///     "</div>"
+    /// ));
+    /// assert_eq!("<p>Raw</p>".to_html_string_pretty(2), "<p>Raw</p>");
+    /// ```
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        let _ = indent;
+        self.to_html_string()
+    }
+
+    /// Write the HTML for this element to the given writer
+    ///
+    /// Structured types such as [`HtmlElement`], [`Table`], and [`HtmlPage`] write their content
+    /// piece by piece, so large documents can be streamed into a file or socket without first
+    /// being rendered into a single `String`. Wrap unbuffered writers, such as a
+    /// [`File`](std::fs::File), in a [`BufWriter`](std::io::BufWriter) to avoid many small writes.
+    ///
+    /// The default implementation writes the result of [`to_html_cow`](Html::to_html_cow).
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::from([[1, 2], [3, 4]]);
+    /// let mut out = Vec::new();
+    /// table.write_html(&mut out).unwrap();
+    ///
+    /// assert_eq!(out, table.to_html_bytes());
+    /// ```
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        w.write_all(self.to_html_cow().as_bytes())
+    }
 }
 
 impl std::fmt::Display for dyn Html {
@@ -151,14 +327,326 @@ impl std::fmt::Display for dyn Html {
 
 impl Html for String {
     fn to_html_string(&self) -> String {
-        self.clone()
+        self.to_html_cow().into_owned()
+    }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
     }
 }
 
 impl Html for &str {
     fn to_html_string(&self) -> String {
-        self.to_string()
+        self.to_html_cow().into_owned()
+    }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+/// Boxed values, including `Box<dyn Html>`, render as their contents
+///
+/// This allows heterogeneous collections of elements to be passed back through the container API
+/// without unboxing them first.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let parts: Vec<Box<dyn Html>> = vec![
+///     Box::new(HtmlElement::new(HtmlTag::Heading1).with_child("Title".into())),
+///     Box::new("<hr/>"),
+/// ];
+///
+/// let mut container = Container::default();
+/// for part in parts {
+///     container.add_html(part);
+/// }
+/// assert_eq!(container.to_html_string(), "<div><h1>Title</h1><hr/></div>");
+/// ```
+impl<T: Html + ?Sized> Html for Box<T> {
+    fn to_html_string(&self) -> String {
+        (**self).to_html_string()
+    }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        (**self).to_html_cow()
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        (**self).render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        (**self).to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        (**self).write_html(w)
+    }
+}
+
+/// Reference-counted values, including `Rc<dyn Html>`, render as their contents
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// # use std::rc::Rc;
+/// let shared: Rc<dyn Html> = Rc::new(HtmlElement::new(HtmlTag::HorizontalRule));
+/// let content = Container::default()
+///     .with_html(Rc::clone(&shared))
+///     .with_html(shared)
+///     .to_html_string();
+/// assert_eq!(content, "<div><hr/><hr/></div>");
+/// ```
+impl<T: Html + ?Sized> Html for Rc<T> {
+    fn to_html_string(&self) -> String {
+        (**self).to_html_string()
+    }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        (**self).to_html_cow()
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        (**self).render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        (**self).to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        (**self).write_html(w)
+    }
+}
+
+/// Atomically reference-counted values, including `Arc<dyn Html>`, render as their contents
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// # use std::sync::Arc;
+/// let shared: Arc<dyn Html> = Arc::new("<hr/>");
+/// let content = Container::default().with_html(shared).to_html_string();
+/// assert_eq!(content, "<div><hr/></div>");
+/// ```
+impl<T: Html + ?Sized> Html for Arc<T> {
+    fn to_html_string(&self) -> String {
+        (**self).to_html_string()
     }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        (**self).to_html_cow()
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        (**self).render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        (**self).to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        (**self).write_html(w)
+    }
+}
+
+/// A string of HTML which is already known to be safe, and will be inserted verbatim
+///
+/// This behaves exactly like inserting a raw string, but makes it explicit at the type level that
+/// the content is trusted, for example because it was produced by a Markdown renderer. Untrusted
+/// text should be passed through [`escape_html`] instead.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let rendered_markdown = PreEscaped::from("<p>Some <em>emphasized</em> text</p>");
+/// let content = Container::default()
+///     .with_html(rendered_markdown)
+///     .to_html_string();
+///
+/// assert_eq!(content, "<div><p>Some <em>emphasized</em> text</p></div>");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PreEscaped(pub String);
+
+impl Html for PreEscaped {
+    fn to_html_string(&self) -> String {
+        self.0.clone()
+    }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.0)
+    }
+}
+
+impl<S: Into<String>> From<S> for PreEscaped {
+    fn from(value: S) -> Self {
+        Self(value.into())
+    }
+}
+
+/// A wrapper which renders its contents once, and reuses the result for every later render
+///
+/// This is useful for large, immutable fragments which are rendered many times, such as a footer
+/// shared by every page of a site. Since the contents cannot be changed after wrapping them, the
+/// cached output can never become stale.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// # use std::rc::Rc;
+/// let footer = Rc::new(Cached::new(
+///     Container::new(ContainerType::Footer).with_paragraph("Copyright 2024"),
+/// ));
+///
+/// let pages: Vec<String> = ["Home", "About"]
+///     .iter()
+///     .map(|title| {
+///         HtmlPage::new()
+///             .with_header(1, title)
+///             .with_html(Rc::clone(&footer))
+///             .to_html_string()
+///     })
+///     .collect();
+///
+/// assert!(pages[1].ends_with("<footer><p>Copyright 2024</p></footer></body></html>"));
+/// assert_eq!(footer.inner().to_html_string(), "<footer><p>Copyright 2024</p></footer>");
+/// ```
+///
+/// The output is stored in a [`OnceLock`], so a `Cached` value can be shared between threads
+/// (for example in an `Arc`) whenever the wrapped content can be. If several threads render it
+/// at the same time, only one of them renders the content.
+///
+/// ```
+/// # use build_html::*;
+/// # use std::sync::Arc;
+/// let banner = Arc::new(Cached::new(HtmlElement::new(HtmlTag::Div).with_paragraph("Sale")));
+/// let worker = {
+///     let banner = Arc::clone(&banner);
+///     std::thread::spawn(move || banner.to_html_string())
+/// };
+/// assert_eq!(worker.join().unwrap(), banner.to_html_string());
+/// ```
+#[derive(Debug)]
+pub struct Cached<H: Html> {
+    inner: H,
+    rendered: OnceLock<String>,
+}
+
+impl<H: Html> Cached<H> {
+    /// Wrap the specified content, which will be rendered the first time it is needed
+    pub fn new(inner: H) -> Self {
+        Self {
+            inner,
+            rendered: OnceLock::new(),
+        }
+    }
+
+    /// Get a reference to the wrapped content
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+
+    /// Unwrap this value, returning the content
+    pub fn into_inner(self) -> H {
+        self.inner
+    }
+}
+
+impl<H: Html> Html for Cached<H> {
+    fn to_html_string(&self) -> String {
+        self.to_html_cow().into_owned()
+    }
+
+    fn to_html_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.rendered.get_or_init(|| self.inner.to_html_string()))
+    }
+}
+
+/// An HTML comment, such as `<!-- build: abc123 -->`
+///
+/// The text is placed between the comment delimiters as given, except for the sequences which the
+/// HTML standard forbids in comments. Text may not start with `>` or `->`, contain `<!--`, `-->`
+/// or `--!>`, or end with `<!-`, so the `<` or `>` in each of these is replaced with a character
+/// reference. This ensures the comment can never end early or leave the rest of the page
+/// commented out.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let content = Container::default()
+///     .with_html(Comment::from(" build: abc123 "))
+///     .with_html(Comment::from("a --> b"))
+///     .to_html_string();
+///
+/// assert_eq!(content, "<div><!-- build: abc123 --><!--a --&gt; b--></div>");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Comment(pub String);
+
+impl Html for Comment {
+    fn to_html_string(&self) -> String {
+        comment_html(&self.0)
+    }
+}
+
+/// Wrap the given text in `<!--` and `-->`, neutralizing any sequence which would end it early
+pub(crate) fn comment_html(text: &str) -> String {
+    let mut text = text
+        .replace("<!--", "&lt;!--")
+        .replace("-->", "--&gt;")
+        .replace("--!>", "--!&gt;");
+    if text.starts_with('>') {
+        text.replace_range(..1, "&gt;");
+    } else if text.starts_with("->") {
+        text.replace_range(1..2, "&gt;");
+    }
+    if text.ends_with("<!-") {
+        let start = text.len() - 3;
+        text.replace_range(start..start + 1, "&lt;");
+    }
+    format!("<!--{}-->", text)
+}
+
+impl<S: Into<String>> From<S> for Comment {
+    fn from(value: S) -> Self {
+        Self(value.into())
+    }
+}
+
+/// Render the provided element to an HTML string
+///
+/// This is equivalent to calling [`Html::to_html_string`], but can be used without importing the
+/// trait, and works directly with trait objects.
+///
+/// # Example
+/// ```
+/// # use build_html::{render, HtmlElement, HtmlTag};
+/// let element = HtmlElement::new(HtmlTag::ParagraphText).with_child("text".into());
+/// assert_eq!(render(&element), "<p>text</p>");
+/// ```
+pub fn render(html: &dyn Html) -> String {
+    html.to_html_string()
+}
+
+/// Render each of the provided elements in order, concatenating them into one HTML string
+///
+/// # Example
+/// ```
+/// # use build_html::{render_all, HtmlElement, HtmlTag};
+/// let heading = HtmlElement::new(HtmlTag::Heading1).with_child("Title".into());
+/// let paragraph = HtmlElement::new(HtmlTag::ParagraphText).with_child("text".into());
+/// assert_eq!(render_all(&[&heading, &paragraph, &"<hr/>"]), "<h1>Title</h1><p>text</p><hr/>");
+/// ```
+pub fn render_all(html: &[&dyn Html]) -> String {
+    let mut rendered = String::new();
+    for element in html {
+        element.render_into(&mut rendered);
+    }
+    rendered
 }
 
 /// Escape the provided string.
@@ -181,16 +669,84 @@ impl Html for &str {
 /// ```
 pub fn escape_html(data: &str) -> String {
     let mut escaped = String::with_capacity(data.len());
+    escape_html_into(data, &mut escaped);
+    escaped
+}
+
+/// Escape the provided string, appending the result to an existing buffer.
+///
+/// This produces the same output as [`escape_html`], but avoids allocating a new `String` for
+/// each value when many values are escaped into the same document.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let mut html = String::from("<p>");
+/// escape_html_into("Fish & Chips", &mut html);
+/// html.push_str("</p>");
+///
+/// assert_eq!(html, "<p>Fish &amp; Chips</p>");
+/// ```
+pub fn escape_html_into(data: &str, out: &mut String) {
+    out.reserve(data.len());
     for c in data.chars() {
         match c {
-            '"' => escaped.push_str("&quot;"),
-            '\'' => escaped.push_str("&#39;"),
-            '&' => escaped.push_str("&amp;"),
-            '<' => escaped.push_str("&lt;"),
-            '>' => escaped.push_str("&gt;"),
-            x => escaped.push(x),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            x => out.push(x),
         }
     }
+}
 
-    escaped
+/// Adapter which displays text with the same characters escaped as [`escape_html`]
+///
+/// Both quote characters are escaped, so the output is also safe within an attribute value of
+/// either [`QuoteStyle`]. Text without any special characters is written without allocating.
+pub(crate) struct Escaped<'a>(pub &'a str);
+
+impl std::fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut rest = self.0;
+        while let Some(i) = rest.find(['"', '\'', '&', '<', '>']) {
+            f.write_str(&rest[..i])?;
+            f.write_str(match rest.as_bytes()[i] {
+                b'"' => "&quot;",
+                b'\'' => "&#39;",
+                b'&' => "&amp;",
+                b'<' => "&lt;",
+                _ => "&gt;",
+            })?;
+            rest = &rest[i + 1..];
+        }
+        f.write_str(rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_text_rules() {
+        // Arrange
+        let cases = [
+            ("plain", "<!--plain-->"),
+            (">start", "<!--&gt;start-->"),
+            ("->start", "<!---&gt;start-->"),
+            ("a <!-- b", "<!--a &lt;!-- b-->"),
+            ("a --> b", "<!--a --&gt; b-->"),
+            ("a --!> b", "<!--a --!&gt; b-->"),
+            ("end <!-", "<!--end &lt;!--->"),
+            ("<!-->", "<!--&lt;!--&gt;-->"),
+            ("->", "<!---&gt;-->"),
+        ];
+
+        // Act / Assert
+        for (text, expected) in cases {
+            assert_eq!(comment_html(text), expected, "comment text {:?}", text);
+        }
+    }
 }
@@ -0,0 +1,192 @@
+//! This module contains the `Picture` struct, used to offer alternative versions of an image
+
+use crate::{Html, HtmlElement, HtmlTag};
+use std::io;
+
+/// A `<picture>` element, which lets the browser choose between several versions of an image
+///
+/// Browsers use the first `<source>` whose media query and MIME type they support, so sources are
+/// always rendered in the order they were added. The fallback `<img>`, which is used when no
+/// source matches, is always rendered last.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let picture = Picture::new("photo.jpg", "A mountain lake")
+///     .with_source("photo-wide.avif", "(min-width: 800px)", "image/avif")
+///     .with_source("photo.webp", "all", "image/webp")
+///     .to_html_string();
+///
+/// assert_eq!(
+///     picture,
+///     concat!(
+///         r#"<picture><source srcset="photo-wide.avif" media="(min-width: 800px)" "#,
+///         r#"type="image/avif"/><source srcset="photo.webp" media="all" type="image/webp"/>"#,
+///         r#"<img src="photo.jpg" alt="A mountain lake"/></picture>"#
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Picture {
+    sources: Vec<HtmlElement>,
+    image: HtmlElement,
+}
+
+impl Html for Picture {
+    fn to_html_string(&self) -> String {
+        let mut html = String::new();
+        self.render_into(&mut html);
+        html
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        buf.push_str("<picture>");
+        for source in self.sources.iter() {
+            source.render_into(buf);
+        }
+        self.image.render_into(buf);
+        buf.push_str("</picture>");
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        w.write_all(b"<picture>")?;
+        for source in self.sources.iter() {
+            source.write_html(w)?;
+        }
+        self.image.write_html(w)?;
+        w.write_all(b"</picture>")
+    }
+}
+
+impl Picture {
+    /// Creates a new `Picture` with the specified fallback image and no sources
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!(
+    ///     Picture::new("logo.png", "Logo").to_html_string(),
+    ///     r#"<picture><img src="logo.png" alt="Logo"/></picture>"#
+    /// );
+    /// ```
+    pub fn new(src: impl ToString, alt: impl ToString) -> Self {
+        Self {
+            sources: Vec::new(),
+            image: HtmlElement::new(HtmlTag::Image)
+                .with_attribute("src", src)
+                .with_attribute("alt", alt),
+        }
+    }
+
+    /// Add a `<source>` with the given `srcset`, `media` query, and MIME `type`
+    ///
+    /// Sources are rendered in the order they are added, after any existing sources.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut picture = Picture::new("chart.png", "Chart");
+    /// picture.add_source("chart-dark.svg", "(prefers-color-scheme: dark)", "image/svg+xml");
+    ///
+    /// assert_eq!(
+    ///     picture.to_html_string(),
+    ///     concat!(
+    ///         r#"<picture><source srcset="chart-dark.svg" media="(prefers-color-scheme: dark)" "#,
+    ///         r#"type="image/svg+xml"/><img src="chart.png" alt="Chart"/></picture>"#
+    ///     )
+    /// );
+    /// ```
+    pub fn add_source(
+        &mut self,
+        srcset: impl ToString,
+        media: impl ToString,
+        mime_type: impl ToString,
+    ) {
+        self.sources.push(
+            HtmlElement::new(HtmlTag::Source)
+                .with_attribute("srcset", srcset)
+                .with_attribute("media", media)
+                .with_attribute("type", mime_type),
+        );
+    }
+
+    /// Add a `<source>` with the given `srcset`, `media` query, and MIME `type`
+    ///
+    /// Sources are rendered in the order they are added, after any existing sources.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let picture = Picture::new("a.jpg", "A")
+    ///     .with_source("a.webp", "all", "image/webp")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     picture,
+    ///     concat!(
+    ///         r#"<picture><source srcset="a.webp" media="all" type="image/webp"/>"#,
+    ///         r#"<img src="a.jpg" alt="A"/></picture>"#
+    ///     )
+    /// );
+    /// ```
+    pub fn with_source(
+        mut self,
+        srcset: impl ToString,
+        media: impl ToString,
+        mime_type: impl ToString,
+    ) -> Self {
+        self.add_source(srcset, media, mime_type);
+        self
+    }
+
+    /// Associates the specified map of attributes with the fallback `<img>` of this `Picture`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let picture = Picture::new("a.jpg", "A")
+    ///     .with_image_attributes([("width", "640"), ("loading", "lazy")])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     picture,
+    ///     r#"<picture><img src="a.jpg" alt="A" width="640" loading="lazy"/></picture>"#
+    /// );
+    /// ```
+    pub fn with_image_attributes<A, S>(mut self, attributes: A) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.image.add_attributes(attributes);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_order() {
+        // Arrange
+        let mut sut = Picture::new("fallback.jpg", "Fallback");
+
+        // Act
+        for format in ["avif", "webp", "png"] {
+            sut.add_source(format!("a.{}", format), "all", format!("image/{}", format));
+        }
+        let html = sut.to_html_string();
+        let mut streamed = Vec::new();
+        sut.write_html(&mut streamed).unwrap();
+
+        // Assert
+        assert_eq!(String::from_utf8(streamed).unwrap(), html);
+        let avif = html.find("a.avif").unwrap();
+        let webp = html.find("a.webp").unwrap();
+        let png = html.find("a.png").unwrap();
+        let img = html.find("<img").unwrap();
+        assert!(avif < webp && webp < png && png < img);
+        assert!(html.ends_with(r#"alt="Fallback"/></picture>"#));
+    }
+}
@@ -0,0 +1,127 @@
+//! This module contains the indented rendering used by [`Html::to_html_string_pretty`]
+
+use crate::{comment_html, Escaped, Html, HtmlChild, HtmlElement, HtmlTag};
+use std::fmt::Write;
+
+/// Whether the children of an element with the given tag should be placed on their own lines
+///
+/// Elements whose content is whitespace-sensitive, such as `<pre>`, must never be listed here.
+fn is_block_container(tag: &HtmlTag) -> bool {
+    matches!(
+        tag,
+        HtmlTag::Article
+            | HtmlTag::Aside
+            | HtmlTag::Blockquote
+            | HtmlTag::Body
+            | HtmlTag::DescriptionList
+            | HtmlTag::DescriptionListDescription
+            | HtmlTag::Details
+            | HtmlTag::Dialog
+            | HtmlTag::Div
+            | HtmlTag::Figure
+            | HtmlTag::Footer
+            | HtmlTag::Header
+            | HtmlTag::HeadingGroup
+            | HtmlTag::ListElement
+            | HtmlTag::Main
+            | HtmlTag::Navigation
+            | HtmlTag::OrderedList
+            | HtmlTag::Picture
+            | HtmlTag::Section
+            | HtmlTag::Table
+            | HtmlTag::TableBody
+            | HtmlTag::TableColumnGroup
+            | HtmlTag::TableFooter
+            | HtmlTag::TableHeader
+            | HtmlTag::TableRow
+            | HtmlTag::Template
+            | HtmlTag::UnorderedList
+    )
+}
+
+impl HtmlElement {
+    /// Write this element to `out` as indented lines, each ending in a newline
+    ///
+    /// Empty raw children, which are only used to force a closing tag, are skipped.
+    pub(crate) fn write_pretty(&self, out: &mut String, indent: usize, depth: usize) {
+        let pad = " ".repeat(indent * depth);
+        let children: Vec<_> = self
+            .children
+            .iter()
+            .filter(|child| !matches!(child, HtmlChild::Raw(r) if r.is_empty()))
+            .collect();
+        let breaks = is_block_container(&self.tag)
+            && (children.len() > 1 || matches!(children.first(), Some(HtmlChild::Element(_))));
+
+        out.push_str(&pad);
+        if !breaks {
+            self.render_into(out);
+            out.push('\n');
+            return;
+        }
+
+        // Writing to a `String` never fails
+        let _ = writeln!(out, "{}", self.open_tag());
+        for child in children {
+            write_pretty_child(child, out, indent, depth + 1);
+        }
+        let _ = writeln!(out, "{}</{}>", pad, self.tag);
+    }
+}
+
+/// Write a child of a block container to `out` as indented lines, each ending in a newline
+pub(crate) fn write_pretty_child(child: &HtmlChild, out: &mut String, indent: usize, depth: usize) {
+    let pad = " ".repeat(indent * depth);
+    // Writing to a `String` never fails
+    match child {
+        HtmlChild::Element(e) => e.write_pretty(out, indent, depth),
+        HtmlChild::Raw(r) if r.is_empty() => {}
+        HtmlChild::Raw(r) => {
+            let _ = writeln!(out, "{}{}", pad, r);
+        }
+        HtmlChild::Text(t) => {
+            let _ = writeln!(out, "{}{}", pad, Escaped(t));
+        }
+        HtmlChild::Comment(c) => {
+            let _ = writeln!(out, "{}{}", pad, comment_html(c));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_table() {
+        // Arrange
+        let sut = crate::Table::from([[1, 2]])
+            .with_header_row(["a", "b"])
+            .with_caption("Numbers");
+
+        // Act
+        let html = sut.to_html_string_pretty(1);
+
+        // Assert
+        assert_eq!(
+            html,
+            concat!(
+                "<table>\n",
+                " <thead>\n",
+                "  <tr>\n",
+                "   <th>a</th>\n",
+                "   <th>b</th>\n",
+                "  </tr>\n",
+                " </thead>\n",
+                " <tbody>\n",
+                "  <tr>\n",
+                "   <td>1</td>\n",
+                "   <td>2</td>\n",
+                "  </tr>\n",
+                " </tbody>\n",
+                " <caption>Numbers</caption>\n",
+                "</table>"
+            )
+        );
+    }
+}
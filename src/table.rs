@@ -3,8 +3,11 @@
 //! Tables are provided using the `Table` struct, and are loaded from 1 and 2D data
 //! structures which implement the `IntoIterator` struct
 
-use crate::{Html, HtmlChild, HtmlContainer, HtmlElement, HtmlTag};
-use std::fmt::{self, Display, Formatter};
+use crate::elements::requires_closing_tag;
+use crate::pretty::write_pretty_child;
+use crate::{Html, HtmlChild, HtmlContainer, HtmlElement, HtmlTag, QuoteStyle};
+use std::fmt::{self, Display, Formatter, Write};
+use std::io;
 
 /// The different types of table cells
 #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
@@ -31,6 +34,48 @@ impl Display for TableCellType {
     }
 }
 
+/// The horizontal alignment of the content of a table cell
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Align {
+    /// Align content to the left edge of the cell
+    Left,
+    /// Center content within the cell
+    Center,
+    /// Align content to the right edge of the cell
+    Right,
+}
+
+impl Align {
+    /// The value of the `text-align` property which applies this alignment
+    fn value(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Center => "center",
+            Self::Right => "right",
+        }
+    }
+
+    /// Merge this alignment into the `style` attribute of the given element
+    ///
+    /// Any existing `text-align` declaration is replaced, and other declarations are kept.
+    fn apply(self, element: &mut HtmlElement) {
+        let mut declarations: Vec<&str> = element
+            .get_attribute("style")
+            .unwrap_or_default()
+            .split(';')
+            .map(str::trim)
+            .filter(|d| {
+                let property = d.split(':').next().unwrap_or_default();
+                !d.is_empty() && !property.trim().eq_ignore_ascii_case("text-align")
+            })
+            .collect();
+        let align = format!("text-align:{}", self.value());
+        declarations.push(&align);
+        let style = declarations.join(";");
+        element.add_attribute("style", style);
+    }
+}
+
 /// A single table cell
 ///
 /// `TableCell` implements [`HtmlContainer`], so it can be filled just like any other
@@ -46,7 +91,47 @@ impl Display for TableCellType {
 ///
 /// assert_eq!(cell, r#"<th id="header-cell" class="headers"><p>Here's a paragraph!</p></th>"#);
 /// ```
-#[derive(Debug)]
+///
+/// Because of this, a cell can hold a complete [`Table`] through
+/// [`with_table`](HtmlContainer::with_table). This is the usual way to lay out HTML emails, where
+/// each column of an outer table contains its own inner table.
+/// ```
+/// # use build_html::*;
+/// let column = |heading: &str, body: &str| {
+///     let heading = TableCell::default().with_header(2, heading);
+///     let body = TableCell::default().with_paragraph(body);
+///     TableCell::default()
+///         .with_attributes([("width", "50%"), ("valign", "top")])
+///         .with_table(
+///             Table::new()
+///                 .with_custom_body_row(TableRow::new().with_cell(heading))
+///                 .with_custom_body_row(TableRow::new().with_cell(body)),
+///         )
+/// };
+/// let layout = Table::new()
+///     .with_attributes([("role", "presentation"), ("width", "600")])
+///     .with_custom_body_row(
+///         TableRow::new()
+///             .with_cell(column("News", "Version 2 is out"))
+///             .with_cell(column("Events", "Meetup on Friday")),
+///     )
+///     .to_html_string();
+///
+/// assert_eq!(
+///     layout,
+///     concat!(
+///         r#"<table role="presentation" width="600"><thead/><tbody><tr>"#,
+///         r#"<td width="50%" valign="top"><table><thead/><tbody>"#,
+///         "<tr><td><h2>News</h2></td></tr><tr><td><p>Version 2 is out</p></td></tr>",
+///         "</tbody></table></td>",
+///         r#"<td width="50%" valign="top"><table><thead/><tbody>"#,
+///         "<tr><td><h2>Events</h2></td></tr><tr><td><p>Meetup on Friday</p></td></tr>",
+///         "</tbody></table></td>",
+///         "</tr></tbody></table>"
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
 pub struct TableCell(HtmlElement);
 
 impl Default for TableCell {
@@ -65,6 +150,10 @@ impl HtmlContainer for TableCell {
     fn add_html<H: Html>(&mut self, html: H) {
         self.0.add_child(HtmlChild::Raw(html.to_html_string()));
     }
+
+    fn add_element(&mut self, element: HtmlElement) {
+        self.0.add_element(element);
+    }
 }
 
 impl TableCell {
@@ -75,7 +164,8 @@ impl TableCell {
 
     /// Set the attributes for this row.
     ///
-    /// Note that this operation overrides all previous invocations of `with_attributes`.
+    /// These attributes are appended to any which have already been set. If an attribute is
+    /// already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -91,15 +181,74 @@ impl TableCell {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        for (k, v) in attributes {
-            self.0.add_attribute(k, v);
-        }
+        self.0.add_attributes(attributes);
+        self
+    }
+
+    /// Set the horizontal alignment of the content of this cell
+    ///
+    /// The alignment is merged into the inline `style` of the cell, keeping any other declarations
+    /// and replacing an earlier alignment.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let out = TableCell::default()
+    ///     .with_align(Align::Right)
+    ///     .with_raw(42)
+    ///     .to_html_string();
+    /// assert_eq!(out, r#"<td style="text-align:right">42</td>"#)
+    /// ```
+    pub fn with_align(mut self, align: Align) -> Self {
+        align.apply(&mut self.0);
+        self
+    }
+
+    /// Add a class to this cell, appending it to any existing `class` attribute
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let out = TableCell::default()
+    ///     .with_class("numeric")
+    ///     .with_class("total")
+    ///     .with_raw(42)
+    ///     .to_html_string();
+    /// assert_eq!(out, r#"<td class="numeric total">42</td>"#)
+    /// ```
+    pub fn with_class(mut self, class: impl ToString) -> Self {
+        self.0.add_class(class);
+        self
+    }
+
+    /// Set the `scope` attribute of this cell, keeping any other attributes
+    ///
+    /// The scope tells screen readers whether a header applies to its column (`"col"`), its row
+    /// (`"row"`), or a group of either (`"colgroup"`, `"rowgroup"`). It is only meaningful on
+    /// [`TableCellType::Header`] cells. Setting a scope again replaces the previous one.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let out = TableCell::new(TableCellType::Header)
+    ///     .with_attributes([("id", "total")])
+    ///     .with_scope("col")
+    ///     .with_scope("row")
+    ///     .with_raw("Total")
+    ///     .to_html_string();
+    /// assert_eq!(out, r#"<th id="total" scope="row">Total</th>"#)
+    /// ```
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.0.add_attribute("scope", scope);
         self
     }
 }
 
 /// A builder for more manual control over individual table elements
 ///
+/// Rows can only contain [`TableCell`]s. Unlike cells, rows do not implement [`HtmlContainer`],
+/// so content cannot accidentally be added directly to a row without being wrapped in a cell.
+///
 /// # Example
 /// ```
 /// # use build_html::*;
@@ -111,7 +260,7 @@ impl TableCell {
 ///
 /// assert_eq!(row, r#"<tr id="my-row"><th>Header</th><td>1</td></tr>"#);
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TableRow(HtmlElement);
 
 impl Default for TableRow {
@@ -146,7 +295,8 @@ impl TableRow {
 
     /// Set the attributes for this row.
     ///
-    /// Note that this operation overrides all previous invocations of `with_attributes`.
+    /// These attributes are appended to any which have already been set. If an attribute is
+    /// already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -162,9 +312,48 @@ impl TableRow {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        for (k, v) in attributes {
-            self.0.add_attribute(k, v);
-        }
+        self.0.add_attributes(attributes);
+        self
+    }
+
+    /// Set the horizontal alignment of the content of every cell in this row
+    ///
+    /// The alignment is merged into the inline `style` of the row, replacing any earlier
+    /// alignment. Data cells inherit it, but browsers center header (`th`) cells by default, and
+    /// a cell with its own alignment keeps it. Use [`TableCell::with_align`] to align those cells.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let out = TableRow::new()
+    ///     .with_align(Align::Center)
+    ///     .with_cell(TableCell::default().with_raw(1))
+    ///     .with_cell(TableCell::default().with_align(Align::Left).with_raw(2))
+    ///     .to_html_string();
+    /// assert_eq!(
+    ///     out,
+    ///     r#"<tr style="text-align:center"><td>1</td><td style="text-align:left">2</td></tr>"#
+    /// );
+    /// ```
+    pub fn with_align(mut self, align: Align) -> Self {
+        align.apply(&mut self.0);
+        self
+    }
+
+    /// Add a class to this row, appending it to any existing `class` attribute
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let out = TableRow::new()
+    ///     .with_attributes([("class", "striped")])
+    ///     .with_class("highlight")
+    ///     .with_cell(TableCell::default().with_raw(1))
+    ///     .to_html_string();
+    /// assert_eq!(out, r#"<tr class="striped highlight"><td>1</td></tr>"#);
+    /// ```
+    pub fn with_class(mut self, class: impl ToString) -> Self {
+        self.0.add_class(class);
         self
     }
 
@@ -195,6 +384,180 @@ impl TableRow {
         self.add_cell(cell);
         self
     }
+
+    /// Add each of the specified cells to this row, in order
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut out = TableRow::new();
+    /// out.add_cells((1..=3).map(|x| TableCell::default().with_raw(x)));
+    /// assert_eq!(out.to_html_string(), "<tr><td>1</td><td>2</td><td>3</td></tr>");
+    /// ```
+    pub fn add_cells(&mut self, cells: impl IntoIterator<Item = TableCell>) {
+        self.0
+            .add_children(cells.into_iter().map(|cell| cell.0.into()))
+    }
+
+    /// Nest each of the specified cells inside this row, in order
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let out = TableRow::new()
+    ///     .with_cells([
+    ///         TableCell::new(TableCellType::Header).with_raw("Total"),
+    ///         TableCell::default().with_raw(42),
+    ///     ])
+    ///     .to_html_string();
+    /// assert_eq!(out, "<tr><th>Total</th><td>42</td></tr>");
+    /// ```
+    pub fn with_cells(mut self, cells: impl IntoIterator<Item = TableCell>) -> Self {
+        self.add_cells(cells);
+        self
+    }
+
+    /// Get the number of cells in this row
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!(TableRow::from([1, 2, 3]).cell_count(), 3);
+    /// assert_eq!(TableRow::new().cell_count(), 0);
+    /// ```
+    pub fn cell_count(&self) -> usize {
+        count_elements(&self.0)
+    }
+
+    /// Consume this row, returning its cells in order
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let cells = TableRow::from(["a", "b"]).into_cells();
+    /// let rendered: Vec<String> = cells.iter().map(|c| c.to_html_string()).collect();
+    /// assert_eq!(rendered, ["<td>a</td>", "<td>b</td>"]);
+    /// ```
+    pub fn into_cells(self) -> Vec<TableCell> {
+        self.0
+            .children
+            .into_iter()
+            .filter_map(|child| match child {
+                HtmlChild::Element(e) => Some(TableCell(e)),
+                HtmlChild::Raw(_) | HtmlChild::Text(_) | HtmlChild::Comment(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Iterate mutably over the children of `element` which are themselves elements
+fn element_children(element: &mut HtmlElement) -> impl Iterator<Item = &mut HtmlElement> {
+    element.children.iter_mut().filter_map(|child| match child {
+        HtmlChild::Element(e) => Some(e),
+        HtmlChild::Raw(_) | HtmlChild::Text(_) | HtmlChild::Comment(_) => None,
+    })
+}
+
+/// The running total of a single table column
+///
+/// Float sums also track the largest number of decimal places seen in the column, so that they
+/// can be displayed without floating point noise such as `0.30000000000000004`.
+#[derive(Debug, Clone, Copy)]
+enum ColumnSum {
+    Integer(i64),
+    Float(f64, usize),
+    NotNumeric,
+}
+
+impl ColumnSum {
+    /// Add the value of the given cell to this sum
+    fn add(self, cell: &HtmlElement) -> Self {
+        let text = match cell.children.as_slice() {
+            [HtmlChild::Raw(text)] | [HtmlChild::Text(text)] => text.trim(),
+            _ => return Self::NotNumeric,
+        };
+
+        // `inf` and `NaN` parse as floats, but are not numbers which can be totalled
+        let float = text.parse::<f64>().ok().filter(|x| x.is_finite());
+        match (self, text.parse::<i64>(), float) {
+            (Self::NotNumeric, _, _) => Self::NotNumeric,
+            (Self::Integer(sum), Ok(x), _) => match sum.checked_add(x) {
+                Some(sum) => Self::Integer(sum),
+                None => Self::Float(sum as f64 + x as f64, 0),
+            },
+            (Self::Integer(sum), Err(_), Some(x)) => {
+                Self::Float(sum as f64 + x, decimal_places(text))
+            }
+            (Self::Float(sum, places), _, Some(x)) => {
+                Self::Float(sum + x, places.max(decimal_places(text)))
+            }
+            (_, _, None) => Self::NotNumeric,
+        }
+    }
+
+    /// The cell displaying this sum in the footer
+    fn to_cell(self) -> TableCell {
+        match self {
+            Self::Integer(x) => TableCell::default().with_raw(x),
+            Self::Float(x, places) if x.is_finite() => {
+                TableCell::default().with_raw(format!("{:.*}", places, x))
+            }
+            Self::Float(..) | Self::NotNumeric => TableCell::default(),
+        }
+    }
+}
+
+/// The number of decimal places written in the given number, such as 2 for `"1.25"`
+fn decimal_places(text: &str) -> usize {
+    let (mantissa, exponent) = text.split_once(['e', 'E']).unwrap_or((text, "0"));
+    let fraction = mantissa.split_once('.').map_or(0, |(_, f)| f.len()) as i64;
+    let exponent = exponent.parse::<i64>().unwrap_or(0);
+    // f64 values have at most 17 significant digits, so more places would only show noise
+    (fraction - exponent).clamp(0, 17) as usize
+}
+
+/// A type that can be converted into a single table row
+///
+/// Implementing this trait allows a collection of domain objects to be turned into a [`Table`]
+/// using [`Table::from_rows`], keeping the mapping from fields to cells in one place.
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// struct Fruit {
+///     name: &'static str,
+///     count: u32,
+/// }
+///
+/// impl ToTableRow for Fruit {
+///     fn to_table_row(&self) -> TableRow {
+///         TableRow::new()
+///             .with_cell(TableCell::new(TableCellType::Header).with_raw(self.name))
+///             .with_cell(TableCell::default().with_raw(self.count))
+///     }
+/// }
+///
+/// let fruit = Fruit { name: "Apples", count: 3 };
+/// assert_eq!(fruit.to_table_row().to_html_string(), "<tr><th>Apples</th><td>3</td></tr>");
+/// ```
+pub trait ToTableRow {
+    /// Convert this value into a table row
+    fn to_table_row(&self) -> TableRow;
+}
+
+impl<T: ToTableRow + ?Sized> ToTableRow for &T {
+    fn to_table_row(&self) -> TableRow {
+        (**self).to_table_row()
+    }
+}
+
+/// Count the children of `element` which are themselves elements
+fn count_elements(element: &HtmlElement) -> usize {
+    element
+        .children
+        .iter()
+        .filter(|child| matches!(child, HtmlChild::Element(_)))
+        .count()
 }
 
 /// Represents an HTML `<table>` element with all its children.
@@ -233,13 +596,17 @@ impl TableRow {
 ///     )
 /// );
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Table {
     table: HtmlElement,
     thead: HtmlElement,
     tbody: HtmlElement,
     tfoot: HtmlElement,
     caption: Option<HtmlElement>,
+    column_group: Option<HtmlElement>,
+    accessible_headers: bool,
+    footer_sum: bool,
+    column_classes: Vec<(usize, String)>,
 }
 
 impl Default for Table {
@@ -250,24 +617,209 @@ impl Default for Table {
 
 impl Html for Table {
     fn to_html_string(&self) -> String {
-        let mut table = self
-            .table
-            .clone()
-            .with_child(self.thead.clone().into())
-            .with_child(self.tbody.clone().into());
+        format!("{}", Streamed(self))
+    }
 
-        // To keep the output the same between versions, only add a footer if there's data in it.
-        // This can be made imperative at the next major version.
-        if !self.tfoot.children.is_empty() || !self.tfoot.attributes.is_empty() {
-            table.add_child(self.tfoot.clone().into());
+    fn render_into(&self, buf: &mut String) {
+        // Writing to a `String` never fails
+        let _ = write!(buf, "{}", Streamed(self));
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        let mut html = String::new();
+        let mut sink = Pretty {
+            out: &mut html,
+            indent,
+            depth: 0,
+        };
+        // Writing to a `String` never fails
+        let _ = self.walk(&mut sink);
+        html.pop();
+        html
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        // Formatting directly into the writer streams each row without building the table first
+        write!(w, "{}", Streamed(self))
+    }
+}
+
+/// Adapter used to display a table on a single line
+struct Streamed<'a>(&'a Table);
+
+impl Display for Streamed<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.walk(&mut Flat(f))
+    }
+}
+
+/// The destination of [`Table::walk`], which receives the parts of a table in document order
+///
+/// Every call to [`open`](TableSink::open) is followed by the children of that element, and then
+/// a matching call to [`close`](TableSink::close).
+trait TableSink {
+    /// Write the opening tag of `element`, which has at least one child element
+    fn open(&mut self, element: &HtmlElement) -> fmt::Result;
+
+    /// Write the closing tag matching the last call to [`open`](TableSink::open)
+    fn close(&mut self, tag: &HtmlTag) -> fmt::Result;
+
+    /// Write a child which the table options do not change
+    fn child(&mut self, child: &HtmlChild) -> fmt::Result;
+
+    /// Write a cell, with the changes made to it by the table options
+    fn cell(&mut self, cell: Cell<'_>) -> fmt::Result;
+
+    /// Write an element which the table options do not change
+    fn element(&mut self, element: &HtmlElement) -> fmt::Result {
+        self.cell(Cell {
+            cell: element,
+            class: None,
+            set: None,
+        })
+    }
+}
+
+/// A [`TableSink`] which writes the table on a single line
+struct Flat<'a, 'b>(&'a mut Formatter<'b>);
+
+impl TableSink for Flat<'_, '_> {
+    fn open(&mut self, element: &HtmlElement) -> fmt::Result {
+        write!(self.0, "{}", element.open_tag())
+    }
+
+    fn close(&mut self, tag: &HtmlTag) -> fmt::Result {
+        write!(self.0, "</{}>", tag)
+    }
+
+    fn child(&mut self, child: &HtmlChild) -> fmt::Result {
+        write!(self.0, "{}", child)
+    }
+
+    fn cell(&mut self, cell: Cell<'_>) -> fmt::Result {
+        write!(self.0, "{}", cell)
+    }
+}
+
+/// A [`TableSink`] which writes the table as indented lines, each ending in a newline
+struct Pretty<'a> {
+    out: &'a mut String,
+    indent: usize,
+    depth: usize,
+}
+
+impl Pretty<'_> {
+    fn pad(&self) -> String {
+        " ".repeat(self.indent * self.depth)
+    }
+}
+
+impl TableSink for Pretty<'_> {
+    fn open(&mut self, element: &HtmlElement) -> fmt::Result {
+        writeln!(self.out, "{}{}", self.pad(), element.open_tag())?;
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn close(&mut self, tag: &HtmlTag) -> fmt::Result {
+        self.depth -= 1;
+        writeln!(self.out, "{}</{}>", self.pad(), tag)
+    }
+
+    fn child(&mut self, child: &HtmlChild) -> fmt::Result {
+        write_pretty_child(child, self.out, self.indent, self.depth);
+        Ok(())
+    }
+
+    fn cell(&mut self, cell: Cell<'_>) -> fmt::Result {
+        if cell.class.is_none() && cell.set.is_none() {
+            cell.cell.write_pretty(self.out, self.indent, self.depth);
+            return Ok(());
         }
+        writeln!(self.out, "{}{}", self.pad(), cell)
+    }
+}
 
-        if let Some(caption) = self.caption.as_ref() {
-            table.add_child(caption.clone().into());
+/// Adapter used to display a cell with `class` appended to its classes, and the attribute `set`
+/// replacing any existing value, as [`HtmlElement::add_class`] and
+/// [`HtmlElement::add_attribute`] would
+#[derive(Clone, Copy)]
+struct Cell<'a> {
+    cell: &'a HtmlElement,
+    class: Option<&'a str>,
+    set: Option<(&'a str, &'a str)>,
+}
+
+impl Display for Cell<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Cell {
+            cell,
+            mut class,
+            mut set,
+        } = *self;
+        if class.is_none() && set.is_none() {
+            return write!(f, "{}", cell);
+        }
+
+        write!(f, "<{}", cell.tag)?;
+        for (k, v) in cell.attributes.iter() {
+            match (class, set) {
+                (Some(added), _) if k == "class" => {
+                    let classes = format!("{} {}", v, added);
+                    QuoteStyle::Double.write_attribute(f, k, &classes)?;
+                    class = None;
+                }
+                (_, Some((key, value))) if k == key => {
+                    QuoteStyle::Double.write_attribute(f, k, value)?;
+                    set = None;
+                }
+                _ => QuoteStyle::Double.write_attribute(f, k, v)?,
+            }
+        }
+        if let Some(added) = class {
+            QuoteStyle::Double.write_attribute(f, "class", added)?;
+        }
+        if let Some((key, value)) = set {
+            QuoteStyle::Double.write_attribute(f, key, value)?;
+        }
+
+        if cell.children.is_empty() && !requires_closing_tag(&cell.tag) {
+            return write!(f, "/>");
+        }
+        write!(f, ">")?;
+        for child in cell.children.iter() {
+            write!(f, "{}", child)?;
         }
+        write!(f, "</{}>", cell.tag)
+    }
+}
+
+/// Write `element` to `sink`, passing each child element to `visit` along with its index among
+/// the child elements
+fn walk_children<F>(sink: &mut dyn TableSink, element: &HtmlElement, mut visit: F) -> fmt::Result
+where
+    F: FnMut(&mut dyn TableSink, usize, &HtmlElement) -> fmt::Result,
+{
+    if !element
+        .children
+        .iter()
+        .any(|child| child.as_element().is_some())
+    {
+        return sink.element(element);
+    }
 
-        table.to_html_string()
+    sink.open(element)?;
+    let mut index = 0;
+    for child in element.children.iter() {
+        match child {
+            HtmlChild::Element(e) => {
+                visit(sink, index, e)?;
+                index += 1;
+            }
+            HtmlChild::Raw(_) | HtmlChild::Text(_) | HtmlChild::Comment(_) => sink.child(child)?,
+        }
     }
+    sink.close(&element.tag)
 }
 
 impl<T> From<T> for Table
@@ -292,13 +844,100 @@ impl Table {
             tbody: HtmlElement::new(HtmlTag::TableBody),
             tfoot: HtmlElement::new(HtmlTag::TableFooter),
             caption: None,
+            column_group: None,
+            accessible_headers: false,
+            footer_sum: false,
+            column_classes: Vec::new(),
         }
     }
 
+    /// Creates a new table with one body row for each of the provided items
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// struct Fruit {
+    ///     name: &'static str,
+    ///     count: u32,
+    /// }
+    ///
+    /// impl ToTableRow for Fruit {
+    ///     fn to_table_row(&self) -> TableRow {
+    ///         TableRow::from([self.name.to_string(), self.count.to_string()])
+    ///     }
+    /// }
+    ///
+    /// let fruits = vec![
+    ///     Fruit { name: "Apples", count: 3 },
+    ///     Fruit { name: "Pears", count: 4 },
+    /// ];
+    /// let table = Table::from_rows(&fruits)
+    ///     .with_header_row(["Name", "Count"])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "<table><thead><tr><th>Name</th><th>Count</th></tr></thead><tbody>",
+    ///         "<tr><td>Apples</td><td>3</td></tr>",
+    ///         "<tr><td>Pears</td><td>4</td></tr>",
+    ///         "</tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn from_rows<I>(items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: ToTableRow,
+    {
+        items.into_iter().fold(Self::new(), |table, item| {
+            table.with_custom_body_row(item.to_table_row())
+        })
+    }
+
+    /// Creates a two-column "label / value" table from an iterator of pairs
+    ///
+    /// Each pair becomes a body row, with the key in a `<th scope="row">` header cell and the
+    /// value in a regular data cell.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::from_pairs([("Name", "Widget"), ("Weight", "2kg")]).to_html_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "<table><thead/><tbody>",
+    ///         r#"<tr><th scope="row">Name</th><td>Widget</td></tr>"#,
+    ///         r#"<tr><th scope="row">Weight</th><td>2kg</td></tr>"#,
+    ///         "</tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn from_pairs<I, K, V>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        pairs.into_iter().fold(Self::new(), |table, (key, value)| {
+            table.with_custom_body_row(
+                TableRow::new()
+                    .with_cell(
+                        TableCell::new(TableCellType::Header)
+                            .with_attributes([("scope", "row")])
+                            .with_raw(key),
+                    )
+                    .with_cell(TableCell::default().with_raw(value)),
+            )
+        })
+    }
+
     /// Associates the specified map of attributes with this `Table`.
     ///
-    /// Note that this operation overrides all previous `add_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -316,15 +955,13 @@ impl Table {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        for (k, v) in attributes {
-            self.table.add_attribute(k, v);
-        }
+        self.table.add_attributes(attributes);
     }
 
     /// Associates the specified map of attributes with this `Table`.
     ///
-    /// Note that this operation overrides all previous `with_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -344,20 +981,61 @@ impl Table {
         self
     }
 
-    /// Set the caption for the table
+    /// Add a single attribute to this `Table`
+    ///
+    /// The attribute is appended to any which have already been set on this `Table`. If it is
+    /// already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
     /// # use build_html::*;
     /// let mut table = Table::new();
-    /// table.add_caption("Demo table");
+    /// table.add_attribute("id", "my-table");
+    /// table.add_attribute("class", "wide");
+    ///
     /// assert_eq!(
     ///     table.to_html_string(),
-    ///     "<table><thead/><tbody/><caption>Demo table</caption></table>",
+    ///     r#"<table id="my-table" class="wide"><thead/><tbody/></table>"#
     /// );
     /// ```
-    pub fn add_caption<H: Html>(&mut self, caption: H) {
-        self.caption = Some(HtmlElement::new(HtmlTag::TableCaption).with_html(caption));
+    pub fn add_attribute(&mut self, k: impl ToString, v: impl ToString) {
+        self.table.add_attribute(k, v);
+    }
+
+    /// Add a single attribute to this `Table`
+    ///
+    /// The attribute is appended to any which have already been set on this `Table`. If it is
+    /// already set, its value is replaced in place instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::new()
+    ///     .with_attributes([("id", "my-table")])
+    ///     .with_attribute("class", "wide")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(table, r#"<table id="my-table" class="wide"><thead/><tbody/></table>"#);
+    /// ```
+    pub fn with_attribute(mut self, k: impl ToString, v: impl ToString) -> Self {
+        self.add_attribute(k, v);
+        self
+    }
+
+    /// Set the caption for the table
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::new();
+    /// table.add_caption("Demo table");
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     "<table><thead/><tbody/><caption>Demo table</caption></table>",
+    /// );
+    /// ```
+    pub fn add_caption<H: Html>(&mut self, caption: H) {
+        self.caption = Some(HtmlElement::new(HtmlTag::TableCaption).with_html(caption));
     }
 
     /// Set the caption for the table
@@ -382,10 +1060,144 @@ impl Table {
         self
     }
 
+    /// Set the caption for the table, along with attributes for the `<caption>` element
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::new();
+    /// table.add_caption_attr("Totals", [("style", "caption-side:bottom")]);
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     r#"<table><thead/><tbody/><caption style="caption-side:bottom">Totals</caption></table>"#,
+    /// );
+    /// ```
+    pub fn add_caption_attr<H, A, S>(&mut self, caption: H, attributes: A)
+    where
+        H: Html,
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_caption(caption);
+        self.caption_mut()
+            .expect("the caption was just set")
+            .add_attributes(attributes);
+    }
+
+    /// Set the caption for the table, along with attributes for the `<caption>` element
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::new()
+    ///     .with_caption_attr("Sales", [("class", "muted")])
+    ///     .to_html_string();
+    /// assert_eq!(
+    ///     table,
+    ///     r#"<table><thead/><tbody/><caption class="muted">Sales</caption></table>"#,
+    /// );
+    /// ```
+    pub fn with_caption_attr<H, A, S>(mut self, caption: H, attributes: A) -> Self
+    where
+        H: Html,
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_caption_attr(caption, attributes);
+        self
+    }
+
+    /// Get a mutable reference to the `<caption>` element of this table, if a caption is set
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::new().with_caption("Sales");
+    /// if let Some(caption) = table.caption_mut() {
+    ///     caption.add_attribute("id", "sales-caption");
+    /// }
+    /// assert!(Table::new().caption_mut().is_none());
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     r#"<table><thead/><tbody/><caption id="sales-caption">Sales</caption></table>"#,
+    /// );
+    /// ```
+    pub fn caption_mut(&mut self) -> Option<&mut HtmlElement> {
+        self.caption.as_mut()
+    }
+
+    /// Set the column group for the table, with one `<col>` for each of the provided attribute sets
+    ///
+    /// The `<colgroup>` is rendered immediately after the opening `<table>` tag, which allows
+    /// widths and classes to be set once per column rather than on every cell. Calling this again
+    /// replaces the existing column group.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::from([[1, 2]]);
+    /// table.add_column_group([vec![("style", "width:20%")], vec![("class", "amount")]]);
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     concat!(
+    ///         r#"<table><colgroup><col style="width:20%"/><col class="amount"/></colgroup>"#,
+    ///         "<thead/><tbody><tr><td>1</td><td>2</td></tr></tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn add_column_group<C, A, S>(&mut self, columns: C)
+    where
+        C: IntoIterator<Item = A>,
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        let column_group =
+            columns
+                .into_iter()
+                .fold(HtmlElement::new(HtmlTag::TableColumnGroup), |group, col| {
+                    group.with_child(
+                        HtmlElement::new(HtmlTag::TableColumn)
+                            .with_attributes(col)
+                            .into(),
+                    )
+                });
+        self.column_group = Some(column_group);
+    }
+
+    /// Set the column group for the table, with one `<col>` for each of the provided attribute sets
+    ///
+    /// An empty list of columns renders an empty `<colgroup/>`, just as an empty `thead` does.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::new()
+    ///     .with_column_group([[("span", "2"), ("class", "names")]])
+    ///     .to_html_string();
+    /// assert_eq!(
+    ///     table,
+    ///     r#"<table><colgroup><col span="2" class="names"/></colgroup><thead/><tbody/></table>"#
+    /// );
+    ///
+    /// let empty = Table::new()
+    ///     .with_column_group(Vec::<[(&str, &str); 0]>::new())
+    ///     .to_html_string();
+    /// assert_eq!(empty, "<table><colgroup/><thead/><tbody/></table>");
+    /// ```
+    pub fn with_column_group<C, A, S>(mut self, columns: C) -> Self
+    where
+        C: IntoIterator<Item = A>,
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.add_column_group(columns);
+        self
+    }
+
     /// Associates the specified map of attributes with the `thead` of this `Table`.
     ///
-    /// Note that this operation overrides all previous `add_thead_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -400,15 +1212,13 @@ impl Table {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        for (k, v) in attributes {
-            self.thead.add_attribute(k, v);
-        }
+        self.thead.add_attributes(attributes);
     }
 
     /// Associates the specified map of attributes with the `thead` of this `Table`.
     ///
-    /// Note that this operation overrides all previous `with_thead_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -416,9 +1226,13 @@ impl Table {
     /// let table = Table::new()
     ///     .with_attributes([("id", "my-table")])
     ///     .with_thead_attributes([("id", "my-thead")])
+    ///     .with_thead_attributes([("class", "sticky")])
     ///     .to_html_string();
     ///
-    /// assert_eq!(table, r#"<table id="my-table"><thead id="my-thead"/><tbody/></table>"#);
+    /// assert_eq!(
+    ///     table,
+    ///     r#"<table id="my-table"><thead id="my-thead" class="sticky"/><tbody/></table>"#
+    /// );
     /// ```
     pub fn with_thead_attributes<A, S>(mut self, attributes: A) -> Self
     where
@@ -431,8 +1245,8 @@ impl Table {
 
     /// Associates the specified map of attributes with the `tbody` of this `Table`.
     ///
-    /// Note that this operation overrides all previous `add_tbody_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -447,15 +1261,13 @@ impl Table {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        for (k, v) in attributes {
-            self.tbody.add_attribute(k, v);
-        }
+        self.tbody.add_attributes(attributes);
     }
 
     /// Associates the specified map of attributes with the `tbody` of this `Table`.
     ///
-    /// Note that this operation overrides all previous `with_tbody_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -478,8 +1290,8 @@ impl Table {
 
     /// Associates the specified map of attributes with the `tfoot` of this `Table`.
     ///
-    /// Note that this operation overrides all previous `add_tfoot_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -494,15 +1306,13 @@ impl Table {
         A: IntoIterator<Item = (S, S)>,
         S: ToString,
     {
-        for (k, v) in attributes {
-            self.tfoot.add_attribute(k, v);
-        }
+        self.tfoot.add_attributes(attributes);
     }
 
     /// Associates the specified map of attributes with the `tfoot` of this `Table`.
     ///
-    /// Note that this operation overrides all previous `with_tfoot_attributes` calls on
-    /// this `Table`
+    /// These attributes are appended to any which have already been set on this `Table`. If an
+    /// attribute is already set, its value is replaced in place instead.
     ///
     /// # Example
     /// ```
@@ -580,6 +1390,66 @@ impl Table {
         self
     }
 
+    /// Adds the specified row to the table header, marking each cell with `scope="col"`
+    ///
+    /// This behaves like [`add_header_row`](Table::add_header_row), but tells screen readers that
+    /// each header cell describes the column beneath it.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::new();
+    /// table.add_header_row_scoped(["Name", "Count"]);
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     concat!(
+    ///         "<table><thead>",
+    ///         r#"<tr><th scope="col">Name</th><th scope="col">Count</th></tr>"#,
+    ///         "</thead><tbody/></table>"
+    ///     )
+    /// )
+    /// ```
+    pub fn add_header_row_scoped<T>(&mut self, row: T)
+    where
+        T: IntoIterator,
+        T::Item: Display,
+    {
+        self.add_custom_header_row(row.into_iter().fold(TableRow::new(), |a, n| {
+            a.with_cell(
+                TableCell::new(TableCellType::Header)
+                    .with_scope("col")
+                    .with_raw(n),
+            )
+        }))
+    }
+
+    /// Adds the specified row to the table header, marking each cell with `scope="col"`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::from([[1, 2]])
+    ///     .with_header_row_scoped(["a", "b"])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "<table><thead>",
+    ///         r#"<tr><th scope="col">a</th><th scope="col">b</th></tr>"#,
+    ///         "</thead><tbody><tr><td>1</td><td>2</td></tr></tbody></table>"
+    ///     )
+    /// )
+    /// ```
+    pub fn with_header_row_scoped<T>(mut self, row: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Display,
+    {
+        self.add_header_row_scoped(row);
+        self
+    }
+
     /// Add the specified row to the table header
     ///
     /// # Example
@@ -756,6 +1626,67 @@ impl Table {
         self
     }
 
+    /// Remove the row at the specified index from the table body, returning it
+    ///
+    /// Returns `None` if the body has no row at that index.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::from([[1, 2], [3, 4], [5, 6]]);
+    /// let removed = table.remove_body_row(1);
+    ///
+    /// assert_eq!(removed.unwrap().to_html_string(), "<tr><td>3</td><td>4</td></tr>");
+    /// assert!(table.remove_body_row(2).is_none());
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     concat!(
+    ///         "<table><thead/><tbody>",
+    ///         "<tr><td>1</td><td>2</td></tr>",
+    ///         "<tr><td>5</td><td>6</td></tr>",
+    ///         "</tbody></table>",
+    ///     ),
+    /// );
+    /// ```
+    pub fn remove_body_row(&mut self, index: usize) -> Option<TableRow> {
+        let position = self
+            .tbody
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| child.as_element().is_some())
+            .nth(index)
+            .map(|(i, _)| i)?;
+        match self.tbody.children.remove(position) {
+            HtmlChild::Element(row) => Some(TableRow(row)),
+            HtmlChild::Raw(_) | HtmlChild::Text(_) | HtmlChild::Comment(_) => {
+                unreachable!("position refers to an element")
+            }
+        }
+    }
+
+    /// Remove all rows from the table body
+    ///
+    /// The header, footer, caption, and any attributes are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::from([[1, 2], [3, 4]])
+    ///     .with_header_row(['a', 'b'])
+    ///     .with_tbody_attributes([("id", "body")]);
+    /// table.clear_body();
+    ///
+    /// assert_eq!(table.body_row_count(), 0);
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     r#"<table><thead><tr><th>a</th><th>b</th></tr></thead><tbody id="body"/></table>"#,
+    /// );
+    /// ```
+    pub fn clear_body(&mut self) {
+        self.tbody.children.clear();
+    }
+
     /// Adds the specified row to the table footer
     ///
     /// Note that no checking is done to ensure that the row is of the proper length
@@ -872,6 +1803,515 @@ impl Table {
         self.add_custom_footer_row(row);
         self
     }
+
+    /// Repeat the last header row of this table in its footer
+    ///
+    /// Every copied cell is rendered as a `<th>`, and any `id` attributes are dropped so that they
+    /// remain unique. The row is copied when this method is called, so later changes to the header
+    /// are not reflected. If no header row has been set, this does nothing.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::from([[1, 2], [3, 4]]);
+    /// table.add_footer_mirroring_header(); // No header yet, so nothing is copied
+    ///
+    /// table.add_header_row(["a", "b"]);
+    /// table.add_footer_mirroring_header();
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     concat!(
+    ///         "<table><thead><tr><th>a</th><th>b</th></tr></thead>",
+    ///         "<tbody><tr><td>1</td><td>2</td></tr><tr><td>3</td><td>4</td></tr></tbody>",
+    ///         "<tfoot><tr><th>a</th><th>b</th></tr></tfoot></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn add_footer_mirroring_header(&mut self) {
+        let last = self
+            .thead
+            .children
+            .iter()
+            .rev()
+            .find_map(HtmlChild::as_element);
+        if let Some(mut row) = last.cloned() {
+            for cell in element_children(&mut row) {
+                cell.tag = HtmlTag::TableHeaderCell;
+                cell.attributes.retain(|(k, _)| k != "id");
+            }
+            self.tfoot.add_child(row.into());
+        }
+    }
+
+    /// Repeat the last header row of this table in its footer
+    ///
+    /// Every copied cell is rendered as a `<th>`, and any `id` attributes are dropped so that they
+    /// remain unique. The row is copied when this method is called, so later changes to the header
+    /// are not reflected. If no header row has been set, this does nothing.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::from([[1, 2]])
+    ///     .with_custom_header_row(
+    ///         TableRow::new()
+    ///             .with_cell(
+    ///                 TableCell::new(TableCellType::Header)
+    ///                     .with_attributes([("id", "a")])
+    ///                     .with_raw("a"),
+    ///             )
+    ///             .with_cell(TableCell::new(TableCellType::Data).with_raw("b")),
+    ///     )
+    ///     .with_footer_mirroring_header()
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         r#"<table><thead><tr><th id="a">a</th><td>b</td></tr></thead>"#,
+    ///         "<tbody><tr><td>1</td><td>2</td></tr></tbody>",
+    ///         "<tfoot><tr><th>a</th><th>b</th></tr></tfoot></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn with_footer_mirroring_header(mut self) -> Self {
+        self.add_footer_mirroring_header();
+        self
+    }
+
+    /// Get the number of rows in the table header
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::from([[1, 2], [3, 4]]).with_header_row(['a', 'b']);
+    /// assert_eq!(table.header_row_count(), 1);
+    /// ```
+    pub fn header_row_count(&self) -> usize {
+        count_elements(&self.thead)
+    }
+
+    /// Get the number of rows in the table body
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::from([[1, 2], [3, 4]]).with_header_row(['a', 'b']);
+    /// assert_eq!(table.body_row_count(), 2);
+    /// ```
+    pub fn body_row_count(&self) -> usize {
+        count_elements(&self.tbody)
+    }
+
+    /// Get the number of columns in the table body
+    ///
+    /// Since rows are not required to be the same length, this is the number of cells in the
+    /// longest body row.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::new()
+    ///     .with_body_row([1, 2])
+    ///     .with_body_row([3, 4, 5]);
+    /// assert_eq!(table.column_count(), 3);
+    /// assert_eq!(Table::new().column_count(), 0);
+    /// ```
+    pub fn column_count(&self) -> usize {
+        self.tbody
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                HtmlChild::Element(row) => Some(count_elements(row)),
+                HtmlChild::Raw(_) | HtmlChild::Text(_) | HtmlChild::Comment(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Stream a table with the given header and body rows directly into `writer`
+    ///
+    /// Rows are pulled from the iterator and written one at a time, so the table never needs to
+    /// be held in memory all at once. This makes it suitable for very large exports. An empty
+    /// header or body is rendered the same way as it would be by [`Table`].
+    ///
+    /// Note that no checking is done to ensure that the rows are of the proper length
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut out = Vec::new();
+    /// let rows = (1..=3).map(|x| [x, x * x]);
+    /// Table::write_rows(["x", "x²"], rows, &mut out).unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     concat!(
+    ///         "<table><thead>",
+    ///         "<tr><th>x</th><th>x²</th></tr>",
+    ///         "</thead><tbody>",
+    ///         "<tr><td>1</td><td>1</td></tr>",
+    ///         "<tr><td>2</td><td>4</td></tr>",
+    ///         "<tr><td>3</td><td>9</td></tr>",
+    ///         "</tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn write_rows<H, I, W>(header: H, rows: I, writer: &mut W) -> io::Result<()>
+    where
+        H: IntoIterator,
+        H::Item: Display,
+        I: IntoIterator,
+        I::Item: IntoIterator,
+        <I::Item as IntoIterator>::Item: Display,
+        W: io::Write,
+    {
+        let header = header.into_iter().fold(TableRow::new(), |a, n| {
+            a.with_cell(TableCell::new(TableCellType::Header).with_raw(n))
+        });
+        if header.0.children.is_empty() {
+            write!(writer, "<table><thead/>")?;
+        } else {
+            write!(writer, "<table><thead>{}</thead>", header.0)?;
+        }
+
+        let mut rows = rows.into_iter().peekable();
+        if rows.peek().is_none() {
+            return write!(writer, "<tbody/></table>");
+        }
+
+        write!(writer, "<tbody>")?;
+        for row in rows {
+            write!(writer, "{}", TableRow::from(row).0)?;
+        }
+        write!(writer, "</tbody></table>")
+    }
+
+    /// Associate each body cell with the header cells of its column
+    ///
+    /// When enabled, every `<th>` in the table header is given an `id` (unless it already has
+    /// one), and every body cell is given a `headers` attribute listing the ids of the header
+    /// cells in the same column. Generated ids are prefixed with the `id` of the table if one has
+    /// been set, or with `header` otherwise.
+    ///
+    /// Columns are matched by position, so cells spanning multiple columns are not accounted for.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::from([[1, 2]]).with_header_row(["A", "B"]);
+    /// table.add_accessible_headers();
+    ///
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     concat!(
+    ///         "<table><thead><tr>",
+    ///         r#"<th id="header-0-0">A</th><th id="header-0-1">B</th>"#,
+    ///         "</tr></thead><tbody><tr>",
+    ///         r#"<td headers="header-0-0">1</td><td headers="header-0-1">2</td>"#,
+    ///         "</tr></tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn add_accessible_headers(&mut self) {
+        self.accessible_headers = true;
+    }
+
+    /// Associate each body cell with the header cells of its column
+    ///
+    /// When enabled, every `<th>` in the table header is given an `id` (unless it already has
+    /// one), and every body cell is given a `headers` attribute listing the ids of the header
+    /// cells in the same column. Generated ids are prefixed with the `id` of the table if one has
+    /// been set, or with `header` otherwise.
+    ///
+    /// Columns are matched by position, so cells spanning multiple columns are not accounted for.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::from([[1, 2]])
+    ///     .with_attributes([("id", "scores")])
+    ///     .with_header_row(["A", "B"])
+    ///     .with_accessible_headers()
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         r#"<table id="scores"><thead><tr>"#,
+    ///         r#"<th id="scores-0-0">A</th><th id="scores-0-1">B</th>"#,
+    ///         "</tr></thead><tbody><tr>",
+    ///         r#"<td headers="scores-0-0">1</td><td headers="scores-0-1">2</td>"#,
+    ///         "</tr></tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn with_accessible_headers(mut self) -> Self {
+        self.add_accessible_headers();
+        self
+    }
+
+    /// Add a row to the table footer containing the sum of each numeric body column
+    ///
+    /// A column is considered numeric if every body cell in it contains only an integer or a
+    /// finite floating point number, so a column containing `inf` or `NaN` is not summed. Columns
+    /// containing only integers are summed as integers, and all other numeric columns are summed
+    /// as floats, shown with as many decimal places as the most precise value in the column.
+    /// Non-numeric columns are left empty in the footer. The sums are computed when the table is
+    /// rendered, so rows added afterward are included.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::from([["Apples", "3", "0.5"], ["Pears", "4", "1.25"]]);
+    /// table.add_footer_sum();
+    ///
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     concat!(
+    ///         "<table><thead/><tbody>",
+    ///         "<tr><td>Apples</td><td>3</td><td>0.5</td></tr>",
+    ///         "<tr><td>Pears</td><td>4</td><td>1.25</td></tr>",
+    ///         "</tbody><tfoot>",
+    ///         "<tr><td/><td>7</td><td>1.75</td></tr>",
+    ///         "</tfoot></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn add_footer_sum(&mut self) {
+        self.footer_sum = true;
+    }
+
+    /// Add a row to the table footer containing the sum of each numeric body column
+    ///
+    /// A column is considered numeric if every body cell in it contains only an integer or a
+    /// finite floating point number, so a column containing `inf` or `NaN` is not summed. Columns
+    /// containing only integers are summed as integers, and all other numeric columns are summed
+    /// as floats, shown with as many decimal places as the most precise value in the column.
+    /// Non-numeric columns are left empty in the footer. The sums are computed when the table is
+    /// rendered, so rows added afterward are included.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::new()
+    ///     .with_header_row(["Item", "Count"])
+    ///     .with_footer_sum()
+    ///     .with_body_row(["Apples", "3"])
+    ///     .with_body_row(["Pears", "4"])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "<table><thead><tr><th>Item</th><th>Count</th></tr></thead><tbody>",
+    ///         "<tr><td>Apples</td><td>3</td></tr>",
+    ///         "<tr><td>Pears</td><td>4</td></tr>",
+    ///         "</tbody><tfoot><tr><td/><td>7</td></tr></tfoot></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn with_footer_sum(mut self) -> Self {
+        self.add_footer_sum();
+        self
+    }
+
+    /// Add the specified class to every body cell in the column with the given index
+    ///
+    /// If a cell already has a `class` attribute, the class is appended to it. The class is
+    /// applied when the table is rendered, so rows added afterward are included. Rows which are
+    /// too short to have a cell at the given index are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let mut table = Table::from([["Apples", "3"], ["Pears", "4"]]);
+    /// table.add_column_class(1, "numeric");
+    ///
+    /// assert_eq!(
+    ///     table.to_html_string(),
+    ///     concat!(
+    ///         "<table><thead/><tbody>",
+    ///         r#"<tr><td>Apples</td><td class="numeric">3</td></tr>"#,
+    ///         r#"<tr><td>Pears</td><td class="numeric">4</td></tr>"#,
+    ///         "</tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn add_column_class(&mut self, index: usize, class: impl ToString) {
+        self.column_classes.push((index, class.to_string()));
+    }
+
+    /// Add the specified class to every body cell in the column with the given index
+    ///
+    /// If a cell already has a `class` attribute, the class is appended to it. The class is
+    /// applied when the table is rendered, so rows added afterward are included. Rows which are
+    /// too short to have a cell at the given index are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let table = Table::new()
+    ///     .with_column_class(0, "name")
+    ///     .with_column_class(1, "numeric")
+    ///     .with_custom_body_row(
+    ///         TableRow::new()
+    ///             .with_cell(TableCell::default().with_raw("Apples"))
+    ///             .with_cell(TableCell::default().with_attributes([("class", "big")]).with_raw(3))
+    ///     )
+    ///     .with_body_row(["Pears"])
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(
+    ///     table,
+    ///     concat!(
+    ///         "<table><thead/><tbody>",
+    ///         r#"<tr><td class="name">Apples</td><td class="big numeric">3</td></tr>"#,
+    ///         r#"<tr><td class="name">Pears</td></tr>"#,
+    ///         "</tbody></table>"
+    ///     )
+    /// );
+    /// ```
+    pub fn with_column_class(mut self, index: usize, class: impl ToString) -> Self {
+        self.add_column_class(index, class);
+        self
+    }
+
+    /// Write this table to `sink`, applying any options which affect rendering to each cell as it
+    /// is written
+    ///
+    /// Both the flat and the pretty output are written by this method, so each option is
+    /// implemented in one place.
+    fn walk(&self, sink: &mut dyn TableSink) -> fmt::Result {
+        let prefix = self.header_prefix();
+        let headers = self.column_headers();
+        let mut classes: Vec<Option<String>> = Vec::new();
+        for (index, class) in self.column_classes.iter() {
+            if classes.len() <= *index {
+                classes.resize(index + 1, None);
+            }
+            match classes[*index].as_mut() {
+                Some(joined) => {
+                    joined.push(' ');
+                    joined.push_str(class);
+                }
+                None => classes[*index] = Some(class.clone()),
+            }
+        }
+
+        sink.open(&self.table)?;
+        if let Some(column_group) = self.column_group.as_ref() {
+            sink.element(column_group)?;
+        }
+
+        walk_children(sink, &self.thead, |sink, r, row| {
+            walk_children(sink, row, |sink, c, cell| {
+                let missing_id = self.accessible_headers
+                    && cell.tag == HtmlTag::TableHeaderCell
+                    && !cell.has_attribute("id");
+                if !missing_id {
+                    return sink.element(cell);
+                }
+
+                let id = format!("{}-{}-{}", prefix, r, c);
+                sink.cell(Cell {
+                    cell,
+                    class: None,
+                    set: Some(("id", &id)),
+                })
+            })
+        })?;
+
+        walk_children(sink, &self.tbody, |sink, _, row| {
+            walk_children(sink, row, |sink, c, cell| {
+                sink.cell(Cell {
+                    cell,
+                    class: classes.get(c).and_then(Option::as_deref),
+                    set: headers
+                        .get(c)
+                        .filter(|ids| !ids.is_empty())
+                        .map(|ids| ("headers", ids.as_str())),
+                })
+            })
+        })?;
+
+        // To keep the output the same between versions, only add a footer if there's data in it.
+        // This can be made imperative at the next major version.
+        if self.footer_sum {
+            sink.open(&self.tfoot)?;
+            for child in self.tfoot.children.iter() {
+                sink.child(child)?;
+            }
+            sink.element(&self.sum_row().0)?;
+            sink.close(&self.tfoot.tag)?;
+        } else if !self.tfoot.children.is_empty() || !self.tfoot.attributes.is_empty() {
+            sink.element(&self.tfoot)?;
+        }
+
+        if let Some(caption) = self.caption.as_ref() {
+            sink.element(caption)?;
+        }
+        sink.close(&self.table.tag)
+    }
+
+    /// Build a row containing the sum of each numeric body column
+    fn sum_row(&self) -> TableRow {
+        let mut sums: Vec<ColumnSum> = Vec::new();
+        for row in self.tbody.children.iter().filter_map(HtmlChild::as_element) {
+            for (c, cell) in row
+                .children
+                .iter()
+                .filter_map(HtmlChild::as_element)
+                .enumerate()
+            {
+                if sums.len() <= c {
+                    sums.push(ColumnSum::Integer(0));
+                }
+                sums[c] = sums[c].add(cell);
+            }
+        }
+
+        sums.into_iter()
+            .fold(TableRow::new(), |row, sum| row.with_cell(sum.to_cell()))
+    }
+
+    /// The prefix of the generated header ids, which is the table's id if it has one
+    fn header_prefix(&self) -> &str {
+        self.table.get_attribute("id").unwrap_or("header")
+    }
+
+    /// The space separated ids of the header cells above each column, if accessible headers are on
+    fn column_headers(&self) -> Vec<String> {
+        let mut columns: Vec<String> = Vec::new();
+        if !self.accessible_headers {
+            return columns;
+        }
+
+        let prefix = self.header_prefix();
+        let rows = self.thead.children.iter().filter_map(HtmlChild::as_element);
+        for (r, row) in rows.enumerate() {
+            let cells = row.children.iter().filter_map(HtmlChild::as_element);
+            for (c, cell) in cells.enumerate() {
+                if cell.tag != HtmlTag::TableHeaderCell {
+                    continue;
+                }
+
+                if columns.len() <= c {
+                    columns.resize(c + 1, String::new());
+                }
+                if !columns[c].is_empty() {
+                    columns[c].push(' ');
+                }
+                match cell.get_attribute("id") {
+                    Some(id) => columns[c].push_str(id),
+                    None => {
+                        let _ = write!(columns[c], "{}-{}-{}", prefix, r, c);
+                    }
+                }
+            }
+        }
+        columns
+    }
 }
 
 #[cfg(test)]
@@ -975,4 +2415,228 @@ mod tests {
                 .collect::<String>()
         );
     }
+
+    #[test]
+    fn test_nested_table_in_cell() {
+        // Arrange
+        let inner = Table::from([[1, 2]])
+            .with_header_row(["a", "b"])
+            .with_caption("Inner")
+            .with_footer_sum();
+        let cell = TableCell::default().with_table(inner.clone());
+
+        // Act
+        let table = Table::new()
+            .with_custom_body_row(TableRow::new().with_cell(cell))
+            .with_accessible_headers();
+
+        // Assert
+        assert_eq!(
+            table.to_html_string(),
+            format!(
+                "<table><thead/><tbody><tr><td>{}</td></tr></tbody></table>",
+                inner.to_html_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_cell_keeps_elements_structured() {
+        // Act
+        let cell = TableCell::default().with_link("/a", "A");
+
+        // Assert
+        let link = cell.0.children[0].as_element().unwrap();
+        assert_eq!(link.get_attribute("href"), Some("/a"));
+        assert_eq!(cell.to_html_string(), r#"<td><a href="/a">A</a></td>"#);
+    }
+
+    #[test]
+    fn test_align_merges_style() {
+        // Arrange
+        let cell =
+            TableCell::default().with_attributes([("style", "color:red; text-align: left;")]);
+        let row = TableRow::new()
+            .with_attributes([("id", "r"), ("style", "font-weight:bold")])
+            .with_align(Align::Center);
+
+        // Act
+        let cell = cell
+            .with_align(Align::Right)
+            .with_align(Align::Center)
+            .with_raw(1);
+
+        // Assert
+        assert_eq!(
+            cell.to_html_string(),
+            r#"<td style="color:red;text-align:center">1</td>"#
+        );
+        assert_eq!(
+            row.to_html_string(),
+            r#"<tr id="r" style="font-weight:bold;text-align:center"/>"#
+        );
+    }
+
+    #[test]
+    fn test_accessible_headers() {
+        // Arrange
+        let table = Table::from([[1, 2, 3]])
+            .with_header_row(["A", "B"])
+            .with_custom_header_row(
+                TableRow::new()
+                    .with_cell(TableCell::new(TableCellType::Header).with_attributes([("id", "a")]))
+                    .with_cell(TableCell::default().with_raw("b"))
+                    .with_cell(TableCell::new(TableCellType::Header).with_raw("c")),
+            )
+            .with_accessible_headers();
+
+        // Act
+        let result = table.to_html_string();
+
+        // Assert
+        assert_eq!(
+            result,
+            concat!(
+                "<table><thead>",
+                r#"<tr><th id="header-0-0">A</th><th id="header-0-1">B</th></tr>"#,
+                r#"<tr><th id="a"/><td>b</td><th id="header-1-2">c</th></tr>"#,
+                "</thead><tbody><tr>",
+                r#"<td headers="header-0-0 a">1</td>"#,
+                r#"<td headers="header-0-1">2</td>"#,
+                r#"<td headers="header-1-2">3</td>"#,
+                "</tr></tbody></table>"
+            )
+        );
+    }
+
+    #[test]
+    fn test_accessible_headers_default_off() {
+        // Arrange
+        let table = Table::from([[1]]).with_header_row(["A"]);
+
+        // Act
+        let result = table.to_html_string();
+
+        // Assert
+        assert_eq!(
+            result,
+            "<table><thead><tr><th>A</th></tr></thead><tbody><tr><td>1</td></tr></tbody></table>"
+        );
+    }
+
+    #[test]
+    fn test_options_apply_to_every_output() {
+        // Arrange
+        let table = Table::from([[1, 2, 3], [4, 5, 6]])
+            .with_attribute("id", "t")
+            .with_column_group([[("span", "3")]])
+            .with_header_row(["A", "B", "C"])
+            .with_custom_body_row(
+                TableRow::new()
+                    .with_cell(TableCell::default().with_class("x").with_raw(7))
+                    .with_cell(TableCell::default().with_attributes([("headers", "old")]))
+                    .with_cell(TableCell::new(TableCellType::Header).with_raw("total")),
+            )
+            .with_footer_row(["f", "g", "h"])
+            .with_caption("Numbers")
+            .with_column_class(0, "first")
+            .with_column_class(0, "name")
+            .with_column_class(1, "second")
+            .with_accessible_headers()
+            .with_footer_sum();
+        let mut written = Vec::new();
+
+        // Act
+        let result = table.to_html_string();
+        let pretty = table.to_html_string_pretty(2);
+        table.write_html(&mut written).unwrap();
+
+        // Assert
+        assert_eq!(String::from_utf8(written).unwrap(), result);
+        assert_eq!(pretty.lines().map(str::trim).collect::<String>(), result);
+        assert!(result.contains(r#"<td class="x first name" headers="t-0-0">7</td>"#));
+        assert!(result.contains(r#"<td headers="t-0-1" class="second"/>"#));
+        assert!(result.contains(r#"<th id="t-0-0">A</th>"#));
+        assert!(pretty.contains("\n      <td class=\"x first name\" headers=\"t-0-0\">7</td>\n"));
+        assert!(pretty.contains("\n  <tfoot>\n    <tr>\n      <th>f</th>\n"));
+    }
+
+    #[test]
+    fn test_write_rows_matches_table() {
+        // Arrange
+        let rows = [[1, 2], [3, 4]];
+        let mut empty = Vec::new();
+        let mut full = Vec::new();
+
+        // Act
+        Table::write_rows(Vec::<&str>::new(), Vec::<[i32; 2]>::new(), &mut empty).unwrap();
+        Table::write_rows(["a", "b"], rows, &mut full).unwrap();
+
+        // Assert
+        assert_eq!(
+            String::from_utf8(empty).unwrap(),
+            Table::new().to_html_string()
+        );
+        assert_eq!(
+            String::from_utf8(full).unwrap(),
+            Table::from(rows)
+                .with_header_row(["a", "b"])
+                .to_html_string()
+        );
+    }
+
+    #[test]
+    fn test_footer_sum() {
+        // Arrange
+        let table = Table::new()
+            .with_body_row(["1", "1.5", "x"])
+            .with_body_row(["2", "2", "3"])
+            .with_body_row(["3"])
+            .with_footer_row(["Total"])
+            .with_footer_sum();
+
+        // Act
+        let result = table.to_html_string();
+
+        // Assert
+        assert!(result.ends_with(concat!(
+            "<tfoot><tr><th>Total</th></tr>",
+            "<tr><td>6</td><td>3.5</td><td/></tr>",
+            "</tfoot></table>"
+        )));
+    }
+
+    #[test]
+    fn test_footer_sum_non_finite() {
+        // Arrange
+        let table = Table::new()
+            .with_body_row(["1", "1", "1", "1.5"])
+            .with_body_row(["inf", "NaN", "-infinity", "1e308"])
+            .with_body_row(["1", "1", "1", "1e308"])
+            .with_footer_sum();
+
+        // Act
+        let result = table.to_html_string();
+
+        // Assert
+        assert!(result.ends_with("<tfoot><tr><td/><td/><td/><td/></tr></tfoot></table>"));
+    }
+
+    #[test]
+    fn test_footer_sum_precision() {
+        // Arrange
+        let table = Table::new()
+            .with_body_row(["0.1", "1.50", "1", "2.5e-1"])
+            .with_body_row(["0.2", "2", "0.125", "1"])
+            .with_footer_sum();
+
+        // Act
+        let result = table.to_html_string();
+
+        // Assert
+        assert!(result.ends_with(concat!(
+            "<tfoot><tr><td>0.3</td><td>3.50</td><td>1.125</td><td>1.25</td></tr>",
+            "</tfoot></table>"
+        )));
+    }
 }
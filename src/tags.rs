@@ -1,10 +1,13 @@
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
 
 /// A list of HTML tags
 ///
 /// This non-comprehensive list of tags is a subset of those listed in the MDN Web Docs
 /// [Html Elements Reference](https://developer.mozilla.org/en-US/docs/Web/HTML/Element).
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// Any other tag, such as a web component, can be created with [`Custom`](HtmlTag::Custom).
+#[derive(Debug, PartialEq, Eq, Clone)]
 #[non_exhaustive]
 pub enum HtmlTag {
     /// A contact address
@@ -13,8 +16,14 @@ pub enum HtmlTag {
     Article,
     /// Indicates side content to the main content
     Aside,
+    /// Isolates text which may be formatted in a different direction from its surroundings
+    Bdi,
+    /// Overrides the direction of the contained text
+    Bdo,
     /// Indicates a blockquote
     Blockquote,
+    /// The body of an HTML document, containing all of its visible content
+    Body,
     /// HTML canvas element
     Canvas,
     /// Used to mark the title of a cited work
@@ -32,6 +41,12 @@ pub enum HtmlTag {
     DescriptionListDescription,
     /// A term to be defined in a description list
     DescriptionListTerm,
+    /// A disclosure widget, which shows its content only when toggled open
+    ///
+    /// The first child should be a [`Summary`](HtmlTag::Summary).
+    Details,
+    /// A dialog box or other interactive component, such as a modal
+    Dialog,
     /// The almighty div -- a generic container with no predefined meaning
     Div,
     /// The caption for the contents of a figure
@@ -64,6 +79,10 @@ pub enum HtmlTag {
     Image,
     /// An inline quote
     InlineQuote,
+    /// A form control which accepts data from the user
+    Input,
+    /// A caption for a form control, always written with a closing tag
+    Label,
     /// A manual line break
     LineBreak,
     /// A link to another page or resource
@@ -78,12 +97,20 @@ pub enum HtmlTag {
     OrderedList,
     /// Paragraph text
     ParagraphText,
+    /// A container offering alternative versions of an image for different displays
+    Picture,
     /// Preformatted text, typically rendered in monospace
     PreformattedText,
     /// A generic section of the document
     Section,
+    /// A form control offering a menu of options, always written with a closing tag
+    Select,
+    /// An alternative media resource for a [`Picture`](HtmlTag::Picture), audio, or video element
+    Source,
     /// A subsection of text
     Span,
+    /// The visible label of a [`Details`](HtmlTag::Details) disclosure widget
+    Summary,
     /// A table element
     Table,
     /// The table body
@@ -104,10 +131,57 @@ pub enum HtmlTag {
     TableHeaderCell,
     /// A table row
     TableRow,
+    /// Inert content which is not rendered, but can be cloned into the page by scripts
+    Template,
+    /// A multi-line plain text form control, always written with a closing tag
+    TextArea,
+    /// A specific moment or period in time, optionally with a machine-readable `datetime`
+    Time,
     /// An unordered, generally bulleted, list
     UnorderedList,
     /// An embedded video element
     Video,
+    /// Any other tag, such as a custom element for a web component
+    ///
+    /// The name can only be created by [`HtmlTag::custom`], which checks that it is a valid custom
+    /// element name, so it is always safe to write into the output.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// let name = String::from("my-widget");
+    /// let widget = HtmlElement::new(HtmlTag::custom(name).unwrap())
+    ///     .with_attribute("size", "large")
+    ///     .with_child("Content".into());
+    ///
+    /// assert_eq!(widget.to_html_string(), r#"<my-widget size="large">Content</my-widget>"#);
+    /// ```
+    Custom(CustomTagName),
+}
+
+/// The name of a [`Custom`](HtmlTag::Custom) tag, which is always a valid custom element name
+///
+/// Names are created with [`HtmlTag::custom`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CustomTagName(String);
+
+impl CustomTagName {
+    /// Get the name of this tag
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// if let HtmlTag::Custom(name) = HtmlTag::custom("my-widget").unwrap() {
+    ///     assert_eq!(name.as_str(), "my-widget");
+    /// }
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for CustomTagName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
 }
 
 impl Display for HtmlTag {
@@ -116,20 +190,142 @@ impl Display for HtmlTag {
     }
 }
 
+impl FromStr for HtmlTag {
+    type Err = ParseHtmlTagError;
+
+    /// Parse a tag from the name used in HTML, such as `"div"` or `"h1"`
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!("div".parse(), Ok(HtmlTag::Div));
+    /// assert_eq!("h1".parse::<HtmlTag>(), Ok(HtmlTag::Heading1));
+    /// assert!("not-a-tag".parse::<HtmlTag>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "address" => Ok(Self::Address),
+            "article" => Ok(Self::Article),
+            "aside" => Ok(Self::Aside),
+            "bdi" => Ok(Self::Bdi),
+            "bdo" => Ok(Self::Bdo),
+            "blockquote" => Ok(Self::Blockquote),
+            "body" => Ok(Self::Body),
+            "canvas" => Ok(Self::Canvas),
+            "cite" => Ok(Self::Cite),
+            "code" => Ok(Self::CodeText),
+            "dl" => Ok(Self::DescriptionList),
+            "dd" => Ok(Self::DescriptionListDescription),
+            "dt" => Ok(Self::DescriptionListTerm),
+            "details" => Ok(Self::Details),
+            "dialog" => Ok(Self::Dialog),
+            "div" => Ok(Self::Div),
+            "figcaption" => Ok(Self::Figcaption),
+            "figure" => Ok(Self::Figure),
+            "footer" => Ok(Self::Footer),
+            "header" => Ok(Self::Header),
+            "h1" => Ok(Self::Heading1),
+            "h2" => Ok(Self::Heading2),
+            "h3" => Ok(Self::Heading3),
+            "h4" => Ok(Self::Heading4),
+            "h5" => Ok(Self::Heading5),
+            "h6" => Ok(Self::Heading6),
+            "hgroup" => Ok(Self::HeadingGroup),
+            "hr" => Ok(Self::HorizontalRule),
+            "iframe" => Ok(Self::Iframe),
+            "img" => Ok(Self::Image),
+            "q" => Ok(Self::InlineQuote),
+            "input" => Ok(Self::Input),
+            "label" => Ok(Self::Label),
+            "br" => Ok(Self::LineBreak),
+            "a" => Ok(Self::Link),
+            "li" => Ok(Self::ListElement),
+            "main" => Ok(Self::Main),
+            "nav" => Ok(Self::Navigation),
+            "ol" => Ok(Self::OrderedList),
+            "p" => Ok(Self::ParagraphText),
+            "picture" => Ok(Self::Picture),
+            "pre" => Ok(Self::PreformattedText),
+            "section" => Ok(Self::Section),
+            "select" => Ok(Self::Select),
+            "source" => Ok(Self::Source),
+            "span" => Ok(Self::Span),
+            "summary" => Ok(Self::Summary),
+            "table" => Ok(Self::Table),
+            "tbody" => Ok(Self::TableBody),
+            "caption" => Ok(Self::TableCaption),
+            "td" => Ok(Self::TableCell),
+            "col" => Ok(Self::TableColumn),
+            "colgroup" => Ok(Self::TableColumnGroup),
+            "tfoot" => Ok(Self::TableFooter),
+            "thead" => Ok(Self::TableHeader),
+            "th" => Ok(Self::TableHeaderCell),
+            "tr" => Ok(Self::TableRow),
+            "template" => Ok(Self::Template),
+            "textarea" => Ok(Self::TextArea),
+            "time" => Ok(Self::Time),
+            "ul" => Ok(Self::UnorderedList),
+            "video" => Ok(Self::Video),
+            _ => Err(ParseHtmlTagError(s.to_owned())),
+        }
+    }
+}
+
+/// The error returned when parsing an unknown tag name into an [`HtmlTag`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseHtmlTagError(String);
+
+impl Display for ParseHtmlTagError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a supported html tag", self.0)
+    }
+}
+
+impl Error for ParseHtmlTagError {}
+
 impl HtmlTag {
+    /// Create a [`Custom`](HtmlTag::Custom) tag, checking that the name is a valid custom element
+    /// name
+    ///
+    /// Valid names start with a lowercase ASCII letter, contain a hyphen, contain no uppercase
+    /// ASCII letters, whitespace, or markup characters, and are not one of the names reserved by
+    /// the HTML standard, such as `font-face`.
+    ///
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!(HtmlTag::custom("my-widget").unwrap().to_string(), "my-widget");
+    /// assert!(HtmlTag::custom("widget").is_err());
+    /// assert!(HtmlTag::custom("div onclick=x").is_err());
+    /// assert!(HtmlTag::custom("").is_err());
+    /// assert!(HtmlTag::custom("font-face").is_err());
+    /// ```
+    pub fn custom(name: impl Into<String>) -> Result<Self, ParseHtmlTagError> {
+        let name = name.into();
+        if is_custom_element_name(&name) {
+            Ok(Self::Custom(CustomTagName(name)))
+        } else {
+            Err(ParseHtmlTagError(name))
+        }
+    }
+
     /// Get the tag code that this tag represents
-    fn as_str(&self) -> &'static str {
+    fn as_str(&self) -> &str {
         match self {
+            Self::Custom(name) => name.as_str(),
             Self::Address => "address",
             Self::Article => "article",
             Self::Aside => "aside",
+            Self::Bdi => "bdi",
+            Self::Bdo => "bdo",
             Self::Blockquote => "blockquote",
+            Self::Body => "body",
             Self::Canvas => "canvas",
             Self::Cite => "cite",
             Self::CodeText => "code",
             Self::DescriptionList => "dl",
             Self::DescriptionListDescription => "dd",
             Self::DescriptionListTerm => "dt",
+            Self::Details => "details",
+            Self::Dialog => "dialog",
             Self::Div => "div",
             Self::Figcaption => "figcaption",
             Self::Figure => "figure",
@@ -146,6 +342,8 @@ impl HtmlTag {
             Self::Iframe => "iframe",
             Self::Image => "img",
             Self::InlineQuote => "q",
+            Self::Input => "input",
+            Self::Label => "label",
             Self::LineBreak => "br",
             Self::Link => "a",
             Self::ListElement => "li",
@@ -153,9 +351,13 @@ impl HtmlTag {
             Self::Navigation => "nav",
             Self::OrderedList => "ol",
             Self::ParagraphText => "p",
+            Self::Picture => "picture",
             Self::PreformattedText => "pre",
             Self::Section => "section",
+            Self::Select => "select",
+            Self::Source => "source",
             Self::Span => "span",
+            Self::Summary => "summary",
             Self::Table => "table",
             Self::TableBody => "tbody",
             Self::TableCaption => "caption",
@@ -166,8 +368,89 @@ impl HtmlTag {
             Self::TableHeader => "thead",
             Self::TableHeaderCell => "th",
             Self::TableRow => "tr",
+            Self::Template => "template",
+            Self::TextArea => "textarea",
+            Self::Time => "time",
             Self::UnorderedList => "ul",
             Self::Video => "video",
         }
     }
 }
+
+/// Whether the name is a [valid custom element name](https://html.spec.whatwg.org/#valid-custom-element-name)
+fn is_custom_element_name(name: &str) -> bool {
+    const RESERVED: [&str; 8] = [
+        "annotation-xml",
+        "color-profile",
+        "font-face",
+        "font-face-src",
+        "font-face-uri",
+        "font-face-format",
+        "font-face-name",
+        "missing-glyph",
+    ];
+
+    let is_name_char = |c: char| {
+        matches!(c,
+            '-' | '.' | '0'..='9' | '_' | 'a'..='z' | '\u{B7}'
+            | '\u{C0}'..='\u{D6}' | '\u{D8}'..='\u{F6}' | '\u{F8}'..='\u{37D}'
+            | '\u{37F}'..='\u{1FFF}' | '\u{200C}'..='\u{200D}' | '\u{203F}'..='\u{2040}'
+            | '\u{2070}'..='\u{218F}' | '\u{2C00}'..='\u{2FEF}' | '\u{3001}'..='\u{D7FF}'
+            | '\u{F900}'..='\u{FDCF}' | '\u{FDF0}'..='\u{FFFD}' | '\u{10000}'..='\u{EFFFF}')
+    };
+
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.contains('-')
+        && name.chars().all(is_name_char)
+        && !RESERVED.contains(&name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_round_trip() {
+        // Arrange
+        let tags = [
+            HtmlTag::Div,
+            HtmlTag::Dialog,
+            HtmlTag::Heading6,
+            HtmlTag::Link,
+            HtmlTag::TableHeaderCell,
+        ];
+
+        // Act / Assert
+        for tag in tags {
+            assert_eq!(tag.to_string().parse(), Ok(tag));
+        }
+        assert_eq!(
+            "blink".parse::<HtmlTag>().unwrap_err().to_string(),
+            "'blink' is not a supported html tag"
+        );
+    }
+
+    #[test]
+    fn test_custom_element_names() {
+        // Arrange
+        let valid = ["my-widget", "x-1", "math-α", "a-b.c_d", "emotion-😍"];
+        let invalid = [
+            "",
+            "widget",
+            "My-widget",
+            "1-widget",
+            "-widget",
+            "my widget",
+            "my-<b>",
+            "annotation-xml",
+        ];
+
+        // Act / Assert
+        for name in valid {
+            assert!(is_custom_element_name(name), "{} should be valid", name);
+        }
+        for name in invalid {
+            assert!(!is_custom_element_name(name), "{} should be invalid", name);
+        }
+    }
+}
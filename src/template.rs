@@ -0,0 +1,93 @@
+//! This module contains the `Template` struct, used to build inert `<template>` content
+
+use crate::{Html, HtmlContainer, HtmlElement, HtmlTag};
+use std::io;
+
+/// A `<template>` element, whose content is parsed but not rendered or executed by the browser
+///
+/// Templates are used by client-side scripts and web components, which clone the inert content
+/// into the page when it is needed. `Template` implements [`HtmlContainer`], so it can hold any
+/// content, just like a [`Container`](crate::Container).
+///
+/// # Example
+/// ```
+/// # use build_html::*;
+/// let row = TableRow::new().with_cell(TableCell::default().with_raw("?"));
+/// let template = Template::new()
+///     .with_attributes([("id", "row")])
+///     .with_html(row)
+///     .to_html_string();
+///
+/// assert_eq!(template, r#"<template id="row"><tr><td>?</td></tr></template>"#);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Template(HtmlElement);
+
+impl Default for Template {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Html for Template {
+    fn to_html_string(&self) -> String {
+        self.0.to_html_string()
+    }
+
+    fn render_into(&self, buf: &mut String) {
+        self.0.render_into(buf)
+    }
+
+    fn to_html_string_pretty(&self, indent: usize) -> String {
+        self.0.to_html_string_pretty(indent)
+    }
+
+    fn write_html(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        self.0.write_html(w)
+    }
+}
+
+impl HtmlContainer for Template {
+    fn add_html<H: Html>(&mut self, html: H) {
+        self.0.add_html(html);
+    }
+
+    fn add_element(&mut self, element: HtmlElement) {
+        self.0.add_element(element);
+    }
+}
+
+impl Template {
+    /// Creates a new, empty `Template`
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// assert_eq!(Template::new().to_html_string(), "<template></template>");
+    /// ```
+    pub fn new() -> Self {
+        // `<template>` is not a void element, so the empty child forces a closing tag
+        Self(HtmlElement::new(HtmlTag::Template).with_child("".into()))
+    }
+
+    /// Associates the specified map of attributes with this `Template`.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let template = Template::new()
+    ///     .with_attributes([("id", "card")])
+    ///     .with_paragraph("Placeholder")
+    ///     .to_html_string();
+    ///
+    /// assert_eq!(template, r#"<template id="card"><p>Placeholder</p></template>"#);
+    /// ```
+    pub fn with_attributes<A, S>(mut self, attributes: A) -> Self
+    where
+        A: IntoIterator<Item = (S, S)>,
+        S: ToString,
+    {
+        self.0.add_attributes(attributes);
+        self
+    }
+}
@@ -0,0 +1,229 @@
+//! This module contains a best-effort checker for common structural mistakes in `HtmlElement`s
+
+use crate::elements::requires_closing_tag;
+use crate::{HtmlChild, HtmlElement, HtmlTag};
+use std::fmt::{self, Display, Formatter};
+
+/// A structural problem found by [`HtmlElement::validate`]
+///
+/// Each warning records the `path` to the offending element, in the same form as
+/// [`HtmlElement::path_to_first`]: a list of child indices leading from the validated element
+/// down to the problem. An empty path refers to the validated element itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationWarning {
+    /// An element which requires a closing tag has no children, so it renders self-closed (e.g.
+    /// `<div/>`), which browsers do not honor for non-void elements
+    SelfClosedNonVoid {
+        /// The location of the element
+        path: Vec<usize>,
+        /// The tag of the element
+        tag: HtmlTag,
+    },
+    /// An `<li>` element whose parent is not an `<ol>` or `<ul>`
+    ListItemOutsideList {
+        /// The location of the element
+        path: Vec<usize>,
+    },
+    /// The same attribute is set more than once on an element, such as with
+    /// [`HtmlElement::push_attribute`]
+    DuplicateAttribute {
+        /// The location of the element
+        path: Vec<usize>,
+        /// The name of the repeated attribute
+        name: String,
+    },
+    /// An `<img>` element has no `alt` attribute
+    MissingAlt {
+        /// The location of the element
+        path: Vec<usize>,
+    },
+    /// A heading is more than one level below the heading before it, such as an `<h3>` directly
+    /// following an `<h1>`
+    SkippedHeadingLevel {
+        /// The location of the element
+        path: Vec<usize>,
+        /// The level of the previous heading
+        previous: u8,
+        /// The level of this heading
+        level: u8,
+    },
+}
+
+impl Display for ValidationWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SelfClosedNonVoid { path, tag } => {
+                write!(f, "{:?}: <{}> is empty and will be self-closed", path, tag)
+            }
+            Self::ListItemOutsideList { path } => {
+                write!(f, "{:?}: <li> is not inside an <ol> or <ul>", path)
+            }
+            Self::DuplicateAttribute { path, name } => {
+                write!(f, "{:?}: attribute '{}' is set more than once", path, name)
+            }
+            Self::MissingAlt { path } => write!(f, "{:?}: <img> has no alt attribute", path),
+            Self::SkippedHeadingLevel {
+                path,
+                previous,
+                level,
+            } => write!(f, "{:?}: <h{}> follows <h{}>", path, level, previous),
+        }
+    }
+}
+
+/// Whether the given tag is a void element, which never has content or a closing tag
+fn is_void(tag: &HtmlTag) -> bool {
+    matches!(
+        tag,
+        HtmlTag::HorizontalRule
+            | HtmlTag::Image
+            | HtmlTag::Input
+            | HtmlTag::LineBreak
+            | HtmlTag::Source
+            | HtmlTag::TableColumn
+    )
+}
+
+/// The level of the given tag, if it is a heading
+fn heading_level(tag: &HtmlTag) -> Option<u8> {
+    match tag {
+        HtmlTag::Heading1 => Some(1),
+        HtmlTag::Heading2 => Some(2),
+        HtmlTag::Heading3 => Some(3),
+        HtmlTag::Heading4 => Some(4),
+        HtmlTag::Heading5 => Some(5),
+        HtmlTag::Heading6 => Some(6),
+        _ => None,
+    }
+}
+
+/// State carried through the recursive walk of the tree
+#[derive(Default)]
+struct Validator {
+    path: Vec<usize>,
+    last_heading: Option<u8>,
+    warnings: Vec<ValidationWarning>,
+}
+
+impl Validator {
+    fn visit(&mut self, element: &HtmlElement, parent: Option<&HtmlTag>) {
+        let tag = &element.tag;
+        if element.children.is_empty() && !is_void(tag) && !requires_closing_tag(tag) {
+            let tag = tag.clone();
+            self.warn(|path| ValidationWarning::SelfClosedNonVoid { path, tag });
+        }
+        if *tag == HtmlTag::ListElement
+            && parent.is_some_and(|p| *p != HtmlTag::OrderedList && *p != HtmlTag::UnorderedList)
+        {
+            self.warn(|path| ValidationWarning::ListItemOutsideList { path });
+        }
+        for (i, (name, _)) in element.attributes.iter().enumerate() {
+            if element.attributes[..i].iter().any(|(k, _)| k == name) {
+                let name = name.clone();
+                self.warn(|path| ValidationWarning::DuplicateAttribute { path, name });
+            }
+        }
+        if *tag == HtmlTag::Image && !element.has_attribute("alt") {
+            self.warn(|path| ValidationWarning::MissingAlt { path });
+        }
+        if let Some(level) = heading_level(tag) {
+            if let Some(previous) = self.last_heading.filter(|p| level > p + 1) {
+                self.warn(|path| ValidationWarning::SkippedHeadingLevel {
+                    path,
+                    previous,
+                    level,
+                });
+            }
+            self.last_heading = Some(level);
+        }
+
+        for (i, child) in element.children.iter().enumerate() {
+            if let HtmlChild::Element(e) = child {
+                self.path.push(i);
+                self.visit(e, Some(tag));
+                self.path.pop();
+            }
+        }
+    }
+
+    fn warn(&mut self, warning: impl FnOnce(Vec<usize>) -> ValidationWarning) {
+        self.warnings.push(warning(self.path.clone()));
+    }
+}
+
+impl HtmlElement {
+    /// Check this element and its descendants for common structural mistakes
+    ///
+    /// This is a best-effort, advisory check rather than a full conformance checker. Only
+    /// structured [`HtmlChild::Element`] children are inspected; raw content is not parsed. See
+    /// [`ValidationWarning`] for the problems which are detected.
+    ///
+    /// # Example
+    /// ```
+    /// # use build_html::*;
+    /// let element = HtmlElement::new(HtmlTag::Div)
+    ///     .with_child(HtmlElement::new_with_text(HtmlTag::Heading1, "Title").into())
+    ///     .with_child(HtmlElement::new_with_text(HtmlTag::Heading3, "Subtitle").into())
+    ///     .with_child(HtmlElement::new(HtmlTag::Image).with_attribute("src", "a.png").into());
+    ///
+    /// assert_eq!(
+    ///     element.validate(),
+    ///     vec![
+    ///         ValidationWarning::SkippedHeadingLevel { path: vec![1], previous: 1, level: 3 },
+    ///         ValidationWarning::MissingAlt { path: vec![2] },
+    ///     ]
+    /// );
+    /// assert!(HtmlElement::new_with_text(HtmlTag::Div, "ok").validate().is_empty());
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut validator = Validator::default();
+        validator.visit(self, None);
+        validator.warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        // Arrange
+        let mut sut = HtmlElement::new(HtmlTag::Div).with_attribute("class", "a");
+        sut.push_attribute("class", "b");
+        let sut = sut
+            .with_child(HtmlElement::new(HtmlTag::Span).into())
+            .with_child(HtmlElement::new(HtmlTag::LineBreak).into())
+            .with_element_child(HtmlTag::ParagraphText, |p| {
+                p.with_child(HtmlElement::new_with_text(HtmlTag::ListElement, "item").into())
+            })
+            .with_element_child(HtmlTag::UnorderedList, |ul| {
+                ul.with_child(HtmlElement::new_with_text(HtmlTag::ListElement, "item").into())
+            })
+            .with_child(HtmlElement::new(HtmlTag::TextArea).into());
+
+        // Act
+        let warnings = sut.validate();
+
+        // Assert
+        assert_eq!(
+            warnings,
+            vec![
+                ValidationWarning::DuplicateAttribute {
+                    path: vec![],
+                    name: "class".to_string()
+                },
+                ValidationWarning::SelfClosedNonVoid {
+                    path: vec![0],
+                    tag: HtmlTag::Span
+                },
+                ValidationWarning::ListItemOutsideList { path: vec![2, 0] },
+            ]
+        );
+        assert_eq!(
+            warnings[2].to_string(),
+            "[2, 0]: <li> is not inside an <ol> or <ul>"
+        );
+    }
+}